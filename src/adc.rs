@@ -0,0 +1,293 @@
+//! ADC codes of arbitrary bit width in unipolar (straight binary) or bipolar (two's complement)
+//! coding.
+//!
+//! An [`AdcFormat`] describes the output of a converter with 1–32 bit. Raw register values are
+//! sign extended to a code with [`AdcFormat::from_raw`] or [`AdcFormat::from_be_bytes`], which is
+//! converted to a resistance by [`conv_d_val_to_r`]. Codes at the rails of the converter are
+//! saturated readings and are rejected as over- or underrange.
+//!
+//! Offset and gain errors of the converter are corrected by an [`AdcCalibration`].
+//!
+//! The fixed resolutions of [`ADCRes`] convert to unipolar formats, so
+//! `AdcFormat::from(ADCRes::B24)` gives the same resistances as [`crate::conv_d_val_to_r`].
+
+use crate::{
+    ADCRes,
+    Error,
+    ResistiveSensor,
+};
+
+/// Coding of the ADC output.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Coding {
+    /// Straight binary, 0 to 2^n - 1.
+    Unipolar,
+    /// Two's complement, -2^(n-1) to 2^(n-1) - 1.
+    Bipolar,
+}
+
+/// Output format of an ADC.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct AdcFormat {
+    bits: u32,
+    coding: Coding,
+}
+
+impl AdcFormat {
+    /// Create the format of an ADC with a resolution of `bits`.
+    ///
+    /// Panics (at compile time when used in a constant) if `bits` is not within 1–32.
+    pub const fn new(bits: u32, coding: Coding) -> Self {
+        assert!(1 <= bits && bits <= 32, "ADC resolution must be 1–32 bit");
+        AdcFormat { bits, coding }
+    }
+
+    /// Create the format of a unipolar ADC with a resolution of `bits`.
+    pub const fn unipolar(bits: u32) -> Self {
+        AdcFormat::new(bits, Coding::Unipolar)
+    }
+
+    /// Create the format of a bipolar ADC with a resolution of `bits`.
+    pub const fn bipolar(bits: u32) -> Self {
+        AdcFormat::new(bits, Coding::Bipolar)
+    }
+
+    /// Resolution in bit.
+    pub const fn bits(&self) -> u32 {
+        self.bits
+    }
+
+    /// Coding of the output.
+    pub const fn coding(&self) -> Coding {
+        self.coding
+    }
+
+    /// Lowest code of the converter.
+    pub const fn min(&self) -> i64 {
+        match self.coding {
+            Coding::Unipolar => 0,
+            Coding::Bipolar => -( 1_i64 << ( self.bits - 1 ) ),
+        }
+    }
+
+    /// Highest code of the converter.
+    pub const fn max(&self) -> i64 {
+        match self.coding {
+            Coding::Unipolar => ( 1_i64 << self.bits ) - 1,
+            Coding::Bipolar => ( 1_i64 << ( self.bits - 1 ) ) - 1,
+        }
+    }
+
+    /// Code corresponding to the reference, i.e. a ratio of 1.
+    ///
+    /// Unipolar converters use 2^n - 1 like [`ADCRes`], bipolar converters 2^(n-1).
+    pub const fn full_scale(&self) -> i64 {
+        match self.coding {
+            Coding::Unipolar => self.max(),
+            Coding::Bipolar => 1_i64 << ( self.bits - 1 ),
+        }
+    }
+
+    /// Code of a right-aligned raw register value, sign extended for bipolar coding.
+    ///
+    /// Bits above the resolution are ignored.
+    pub const fn from_raw(&self, raw: u64) -> i64 {
+        let raw = raw & ( ( 1_u64 << self.bits ) - 1 );
+        match self.coding {
+            Coding::Bipolar if raw >> ( self.bits - 1 ) == 1 => raw as i64 - ( 1_i64 << self.bits ),
+            _ => raw as i64,
+        }
+    }
+
+    /// Code of a right-aligned register value read as big-endian bytes, most significant byte first.
+    pub fn from_be_bytes(&self, bytes: &[u8]) -> i64 {
+        self.from_raw(bytes.iter().fold(0_u64, |raw, byte| raw << 8 | *byte as u64))
+    }
+
+    /// Check that a code is within the converter's range and not at one of its rails.
+    pub fn check(&self, d_val: i64) -> Result<i64, Error> {
+        match d_val {
+            d if d <= self.min() => Err(Error::AdcUnderrange { d_val, min: self.min() }),
+            d if d >= self.max() => Err(Error::AdcOverrange { d_val, max: self.max() }),
+            d => Ok(d),
+        }
+    }
+}
+
+impl From<ADCRes> for AdcFormat {
+    fn from(res: ADCRes) -> Self {
+        AdcFormat::unipolar(32 - ( res as u32 ).leading_zeros())
+    }
+}
+
+/// Convert code of relative measurement for an ADC of any format to resistance.
+///
+/// Codes at the rails of the converter are rejected, negative codes of bipolar converters result
+/// in negative resistances.
+#[allow(dead_code)]
+pub fn conv_d_val_to_r(d_val: i64, r_ref: u32, format: AdcFormat, pga_gain: u32) -> Result<f32, Error> {
+    if pga_gain == 0 {
+        return Err(Error::ZeroGain);
+    }
+    let d_val = format.check(d_val)?;
+    Ok(( d_val as f64 * r_ref as f64 / ( format.full_scale() as f64 * pga_gain as f64 ) ) as f32)
+}
+
+/// Offset and gain correction of an ADC, e.g. from a system calibration.
+///
+/// The raw code is modelled as `d_val = gain · d_ideal + offset`, where `d_ideal` is the code of an
+/// ideal converter. Both values can be determined from two measurements of known resistances with
+/// [`AdcCalibration::from_measurements`], e.g. with shorted inputs and a precision resistor, and
+/// stored per board.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct AdcCalibration {
+    /// Offset in codes.
+    pub offset: f32,
+    /// Gain relative to an ideal converter.
+    pub gain: f32,
+}
+
+impl AdcCalibration {
+    /// Calibration of an ideal converter.
+    pub const IDENTITY: AdcCalibration = AdcCalibration::new(0_f32, 1_f32);
+
+    /// Create a calibration from the offset in codes and the relative gain.
+    pub const fn new(offset: f32, gain: f32) -> Self {
+        AdcCalibration { offset, gain }
+    }
+
+    /// Determine offset and gain from the codes `d_val_1` and `d_val_2` measured for the known
+    /// resistances `r_1` and `r_2` in Ω.
+    pub fn from_measurements(
+        (r_1, d_val_1): (f32, i64),
+        (r_2, d_val_2): (f32, i64),
+        r_ref: u32,
+        format: AdcFormat,
+        pga_gain: u32,
+    ) -> Result<Self, Error> {
+        if pga_gain == 0 {
+            return Err(Error::ZeroGain);
+        }
+        // codes of an ideal converter for the known resistances
+        let scale = format.full_scale() as f64 * pga_gain as f64 / r_ref as f64;
+        let (ideal_1, ideal_2) = (r_1 as f64 * scale, r_2 as f64 * scale);
+
+        let gain = ( d_val_2 - d_val_1 ) as f64 / ( ideal_2 - ideal_1 );
+        if !( gain.is_finite() && gain > 0_f64 ) {
+            return Err(Error::InsufficientData); // resistances or codes do not differ
+        }
+        let offset = d_val_1 as f64 - gain * ideal_1;
+        Ok(AdcCalibration { offset: offset as f32, gain: gain as f32 })
+    }
+
+    /// Correct a code to the code of an ideal converter.
+    pub fn apply(&self, d_val: i64) -> f64 {
+        ( d_val as f64 - self.offset as f64 ) / self.gain as f64
+    }
+
+    /// Convert code of relative measurement to resistance after correcting offset and gain, see
+    /// [`conv_d_val_to_r`].
+    ///
+    /// Codes at the rails of the converter are rejected before the correction.
+    pub fn conv_d_val_to_r(&self, d_val: i64, r_ref: u32, format: AdcFormat, pga_gain: u32) -> Result<f32, Error> {
+        if pga_gain == 0 {
+            return Err(Error::ZeroGain);
+        }
+        let d_val = self.apply(format.check(d_val)?);
+        Ok(( d_val * r_ref as f64 / ( format.full_scale() as f64 * pga_gain as f64 ) ) as f32)
+    }
+
+    /// Convert code of relative measurement to temperature of a sensor in °C after correcting
+    /// offset and gain.
+    pub fn conv_d_val_to_t(
+        &self,
+        d_val: i64,
+        r_ref: u32,
+        format: AdcFormat,
+        pga_gain: u32,
+        sensor: &impl ResistiveSensor,
+    ) -> Result<f32, Error> {
+        sensor.temperature_at(self.conv_d_val_to_r(d_val, r_ref, format, pga_gain)?)
+    }
+}
+
+impl Default for AdcCalibration {
+    fn default() -> Self {
+        AdcCalibration::IDENTITY
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use libm::fabsf;
+
+    #[test]
+    fn sign_extension() {
+        let format = AdcFormat::bipolar(17);
+        assert_eq!(format.from_raw(0x0_FFFF), 65_535);
+        assert_eq!(format.from_raw(0x1_0000), -65_536);
+        assert_eq!(format.from_raw(0x1_FFFF), -1);
+        assert_eq!(format.from_raw(0xFFFE_0005), 5);
+        assert_eq!(AdcFormat::bipolar(24).from_be_bytes(&[0xFF, 0xFF, 0xFE]), -2);
+        assert_eq!(AdcFormat::unipolar(24).from_be_bytes(&[0xFF, 0xFF, 0xFE]), 16_777_214);
+        assert_eq!(AdcFormat::bipolar(32).from_be_bytes(&[0x80, 0, 0, 0]), i32::MIN as i64);
+        assert_eq!(AdcFormat::unipolar(15).from_raw(0x7FFF), 32_767);
+    }
+
+    #[test]
+    fn resistance_conversion() {
+        // the fixed resolutions convert to the same resistances
+        for res in [ADCRes::B8, ADCRes::B14, ADCRes::B24] {
+            let format = AdcFormat::from(res);
+            assert_eq!(format.max(), res as u32 as i64);
+            let d_val = res as u32 / 3;
+            let r = crate::conv_d_val_to_r(d_val, 400, res, 2).unwrap();
+            assert!(fabsf(conv_d_val_to_r(d_val as i64, 400, format, 2).unwrap() - r) < 1e-4);
+        }
+
+        let format = AdcFormat::bipolar(24);
+        assert!(fabsf(conv_d_val_to_r(2_097_152, 400, format, 1).unwrap() - 100_f32) < 1e-4);
+        assert!(fabsf(conv_d_val_to_r(-2_097_152, 400, format, 1).unwrap() + 100_f32) < 1e-4);
+    }
+
+    #[test]
+    fn rails() {
+        let format = AdcFormat::bipolar(16);
+        assert_eq!(conv_d_val_to_r(32_767, 400, format, 1), Err(Error::AdcOverrange { d_val: 32_767, max: 32_767 }));
+        assert_eq!(conv_d_val_to_r(-32_768, 400, format, 1), Err(Error::AdcUnderrange { d_val: -32_768, min: -32_768 }));
+        assert!(conv_d_val_to_r(32_766, 400, format, 1).is_ok());
+
+        let format = AdcFormat::unipolar(12);
+        assert!(matches!(conv_d_val_to_r(0, 400, format, 1), Err(Error::AdcUnderrange { .. })));
+        assert!(matches!(conv_d_val_to_r(4_095, 400, format, 1), Err(Error::AdcOverrange { .. })));
+        assert!(matches!(conv_d_val_to_r(100, 400, format, 0), Err(Error::ZeroGain)));
+    }
+
+    #[test]
+    fn calibration() {
+        use crate::RTDType;
+
+        // board with an offset of 120 codes and 0.2 % excess gain
+        let format = AdcFormat::unipolar(24);
+        let board = |r: f32| ( 120_f64 + 1.002 * r as f64 / 400_f64 * format.full_scale() as f64 ) as i64;
+
+        let cal = AdcCalibration::from_measurements((0_f32, board(0_f32)), (390_f32, board(390_f32)), 400, format, 1).unwrap();
+        assert!(fabsf(cal.offset - 120_f32) < 1_f32);
+        assert!(fabsf(cal.gain - 1.002) < 1e-6);
+
+        let r = cal.conv_d_val_to_r(board(100_f32), 400, format, 1).unwrap();
+        assert!(fabsf(r - 100_f32) < 1e-4);
+        let t = cal.conv_d_val_to_t(board(138.5055), 400, format, 1, &RTDType::PT100).unwrap();
+        assert!(fabsf(t - 100_f32) < 1e-3);
+
+        // uncorrected, the gain error is about 0.5 K at 100°C
+        let r = AdcCalibration::IDENTITY.conv_d_val_to_r(board(138.5055), 400, format, 1).unwrap();
+        assert!(fabsf(r - 138.5055) > 0.2);
+
+        assert!(matches!(
+            AdcCalibration::from_measurements((100_f32, 1_000), (100_f32, 1_000), 400, format, 1),
+            Err(Error::InsufficientData),
+        ));
+    }
+}
@@ -0,0 +1,924 @@
+//! Drivers for TI delta-sigma ADCs with IDAC excitation, based on the `embedded-hal` 1.0 traits.
+//!
+//! Requires the `ads1220` or `ads124s08` feature. Both converters measure the RTD ratiometrically:
+//! the excitation current of the IDACs flows through the RTD and the reference resistor, whose
+//! voltage is the reference of the ADC. The 24 bit bipolar code is converted to a resistance with
+//! [`adc::conv_d_val_to_r`] and to temperature with the sensor.
+//!
+//! For three-wire sensors a second IDAC drives the compensating lead, so the lead resistances
+//! cancel and both currents flow through the reference resistor. A mismatch of the IDACs is
+//! removed by chopping: a second conversion with the IDAC outputs swapped is added to the first.
+//!
+//! ```rust,ignore
+//! let mut adc = Ads1220::new(spi, drdy, 1620);
+//! adc.configure(RtdConfig {
+//!     input: (1, 2),
+//!     idac_1: 0,
+//!     idac_2: Some(3),
+//!     current: IdacCurrent::UA500,
+//!     gain: Gain::X16,
+//!     reference: Reference::Ref0,
+//! })?;
+//! let t = adc.read_temperature(&RTDType::PT100, &mut delay)?;
+//! ```
+//!
+//! Conversions are single-shot at 20 SPS with 50 Hz and 60 Hz rejection. The end of a conversion
+//! is detected with the DRDY pin.
+
+use core::fmt;
+
+use embedded_hal::{
+    delay::DelayNs,
+    digital::InputPin,
+    spi::{
+        Operation,
+        SpiDevice,
+    },
+};
+
+use crate::{
+    adc::{
+        self,
+        AdcFormat,
+    },
+    ResistiveSensor,
+};
+
+/// Format of the conversion results.
+const FORMAT: AdcFormat = AdcFormat::bipolar(24);
+/// Timeout while waiting for DRDY in ms.
+const DRDY_TIMEOUT_MS: u32 = 200;
+
+/// Errors of the drivers.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Error<S, P> {
+    /// Error of the SPI bus.
+    Spi(S),
+    /// Error of the DRDY pin.
+    Pin(P),
+    /// The configuration uses inputs the converter does not support.
+    InvalidConfig,
+    /// DRDY did not signal the end of the conversion.
+    Timeout,
+    /// The code could not be converted to resistance or temperature.
+    Conversion(crate::Error),
+}
+
+impl<S: fmt::Debug, P: fmt::Debug> fmt::Display for Error<S, P> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::Spi(error) => write!(f, "SPI error: {error:?}"),
+            Error::Pin(error) => write!(f, "DRDY pin error: {error:?}"),
+            Error::InvalidConfig => write!(f, "configuration not supported by the converter"),
+            Error::Timeout => write!(f, "DRDY timed out"),
+            Error::Conversion(error) => write!(f, "conversion failed: {error}"),
+        }
+    }
+}
+
+impl<S: fmt::Debug, P: fmt::Debug> core::error::Error for Error<S, P> {
+    fn source(&self) -> Option<&(dyn core::error::Error + 'static)> {
+        match self {
+            Error::Conversion(error) => Some(error),
+            _ => None,
+        }
+    }
+}
+
+impl<S, P> From<crate::Error> for Error<S, P> {
+    fn from(error: crate::Error) -> Self {
+        Error::Conversion(error)
+    }
+}
+
+/// Gain of the PGA.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Gain {
+    X1,
+    X2,
+    X4,
+    X8,
+    X16,
+    X32,
+    X64,
+    X128,
+}
+
+impl Gain {
+    /// Register value of the gain, equal for both converters.
+    const fn bits(&self) -> u8 {
+        *self as u8
+    }
+
+    /// Gain as factor.
+    pub const fn value(&self) -> u32 {
+        1 << *self as u32
+    }
+}
+
+/// Excitation current of the IDACs.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum IdacCurrent {
+    UA10,
+    UA50,
+    UA100,
+    UA250,
+    UA500,
+    UA1000,
+    UA1500,
+}
+
+/// External reference input connected to the reference resistor.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Reference {
+    /// REFP0/REFN0.
+    Ref0,
+    /// REFP1/REFN1.
+    Ref1,
+}
+
+/// Configuration of an RTD measurement. Inputs are given by their number, e.g. `2` for AIN2.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RtdConfig {
+    /// Positive and negative input of the measurement.
+    pub input: (u8, u8),
+    /// Output of the first IDAC, connected to the RTD.
+    pub idac_1: u8,
+    /// Output of the second IDAC, connected to the compensating lead of a three-wire sensor.
+    pub idac_2: Option<u8>,
+    pub current: IdacCurrent,
+    pub gain: Gain,
+    pub reference: Reference,
+}
+
+/// Placeholder configuration of a driver before [`RtdAdc::config`] is written.
+const UNCONFIGURED: RtdConfig = RtdConfig {
+    input: (0, 1),
+    idac_1: 0,
+    idac_2: None,
+    current: IdacCurrent::UA10,
+    gain: Gain::X1,
+    reference: Reference::Ref0,
+};
+
+/// Common measurement pipeline of the converters.
+pub trait RtdAdc {
+    type SpiError;
+    type PinError;
+
+    /// Configuration of the measurement.
+    fn config(&self) -> &RtdConfig;
+
+    /// Resistance of the reference resistor in Ω.
+    fn r_ref(&self) -> u32;
+
+    /// Connect the IDACs to the outputs `idac_1` and `idac_2`.
+    fn route_idacs(&mut self, idac_1: u8, idac_2: Option<u8>) -> Result<(), Error<Self::SpiError, Self::PinError>>;
+
+    /// Start a single conversion, wait for its end and read the code.
+    fn convert(&mut self, delay: &mut impl DelayNs) -> Result<i64, Error<Self::SpiError, Self::PinError>>;
+
+    /// Measure the resistance of the RTD in Ω, chopping the IDACs for three-wire sensors.
+    fn read_resistance(&mut self, delay: &mut impl DelayNs) -> Result<f32, Error<Self::SpiError, Self::PinError>> {
+        let config = *self.config();
+        let (r_ref, gain) = (self.r_ref(), config.gain.value());
+        let r = |d_val| adc::conv_d_val_to_r(d_val, r_ref, FORMAT, gain);
+        match config.idac_2 {
+            None => Ok(r(self.convert(delay)?)?),
+            Some(idac_2) => {
+                // R = R_ref · (d_1 + d_2) / (full scale · gain), as both currents flow through R_ref
+                let d_1 = self.convert(delay)?;
+                self.route_idacs(idac_2, Some(config.idac_1))?;
+                let d_2 = self.convert(delay);
+                self.route_idacs(config.idac_1, Some(idac_2))?;
+                Ok(r(d_1)? + r(d_2?)?)
+            },
+        }
+    }
+
+    /// Measure the temperature of a sensor in °C.
+    fn read_temperature(
+        &mut self,
+        sensor: &impl ResistiveSensor,
+        delay: &mut impl DelayNs,
+    ) -> Result<f32, Error<Self::SpiError, Self::PinError>> {
+        Ok(sensor.temperature_at(self.read_resistance(delay)?)?)
+    }
+}
+
+/// Wait for DRDY to go low.
+fn wait_drdy<S, P: InputPin>(drdy: &mut P, delay: &mut impl DelayNs) -> Result<(), Error<S, P::Error>> {
+    for _ in 0..DRDY_TIMEOUT_MS {
+        if drdy.is_low().map_err(Error::Pin)? {
+            return Ok(());
+        }
+        delay.delay_ms(1);
+    }
+    Err(Error::Timeout)
+}
+
+/// Write a command, optionally followed by data.
+fn command<S: SpiDevice, P>(spi: &mut S, bytes: &[u8]) -> Result<(), Error<S::Error, P>> {
+    spi.write(bytes).map_err(Error::Spi)
+}
+
+/// Read a 24 bit conversion result after a command.
+fn read_data<S: SpiDevice, P>(spi: &mut S, command: u8) -> Result<i64, Error<S::Error, P>> {
+    let mut buf = [0_u8; 3];
+    spi.transaction(&mut [Operation::Write(&[command]), Operation::Read(&mut buf)]).map_err(Error::Spi)?;
+    Ok(FORMAT.from_be_bytes(&buf))
+}
+
+/// Async variants of the drivers, based on the `embedded-hal-async` 1.0 traits.
+///
+/// Requires the `async` feature. The end of a conversion is awaited at the DRDY pin instead of
+/// polling it, so other tasks can run during the conversion. The pin never goes low if the
+/// converter is not powered, so wrap the futures in a timeout of the executor where this matters.
+///
+/// ```rust,ignore
+/// let mut adc = asynch::Ads1220::new(spi, drdy, 1620);
+/// adc.configure(config).await?;
+/// let t = adc.read_temperature(&RTDType::PT100).await?;
+/// ```
+#[cfg(feature = "async")]
+pub mod asynch {
+    use embedded_hal_async::{
+        digital::Wait,
+        spi::{
+            Operation,
+            SpiDevice,
+        },
+    };
+
+    use super::{
+        adc,
+        Error,
+        RtdConfig,
+        FORMAT,
+    };
+    use crate::ResistiveSensor;
+
+    #[cfg(feature = "ads1220")]
+    pub use super::ads1220::asynch::Ads1220;
+    #[cfg(feature = "ads124s08")]
+    pub use super::ads124s08::asynch::Ads124s08;
+
+    /// Common measurement pipeline of the converters.
+    #[allow(async_fn_in_trait)]
+    pub trait RtdAdc {
+        type SpiError;
+        type PinError;
+
+        /// Configuration of the measurement.
+        fn config(&self) -> &RtdConfig;
+
+        /// Resistance of the reference resistor in Ω.
+        fn r_ref(&self) -> u32;
+
+        /// Connect the IDACs to the outputs `idac_1` and `idac_2`.
+        async fn route_idacs(&mut self, idac_1: u8, idac_2: Option<u8>) -> Result<(), Error<Self::SpiError, Self::PinError>>;
+
+        /// Start a single conversion, await its end and read the code.
+        async fn convert(&mut self) -> Result<i64, Error<Self::SpiError, Self::PinError>>;
+
+        /// Measure the resistance of the RTD in Ω, chopping the IDACs for three-wire sensors.
+        async fn read_resistance(&mut self) -> Result<f32, Error<Self::SpiError, Self::PinError>> {
+            let config = *self.config();
+            let (r_ref, gain) = (self.r_ref(), config.gain.value());
+            let r = |d_val| adc::conv_d_val_to_r(d_val, r_ref, FORMAT, gain);
+            match config.idac_2 {
+                None => Ok(r(self.convert().await?)?),
+                Some(idac_2) => {
+                    let d_1 = self.convert().await?;
+                    self.route_idacs(idac_2, Some(config.idac_1)).await?;
+                    let d_2 = self.convert().await;
+                    self.route_idacs(config.idac_1, Some(idac_2)).await?;
+                    Ok(r(d_1)? + r(d_2?)?)
+                },
+            }
+        }
+
+        /// Measure the temperature of a sensor in °C.
+        async fn read_temperature(
+            &mut self,
+            sensor: &impl ResistiveSensor,
+        ) -> Result<f32, Error<Self::SpiError, Self::PinError>> {
+            Ok(sensor.temperature_at(self.read_resistance().await?)?)
+        }
+    }
+
+    /// Wait for DRDY to go low.
+    pub(super) async fn wait_drdy<S, P: Wait>(drdy: &mut P) -> Result<(), Error<S, P::Error>> {
+        drdy.wait_for_low().await.map_err(Error::Pin)
+    }
+
+    /// Write a command, optionally followed by data.
+    pub(super) async fn command<S: SpiDevice, P>(spi: &mut S, bytes: &[u8]) -> Result<(), Error<S::Error, P>> {
+        spi.write(bytes).await.map_err(Error::Spi)
+    }
+
+    /// Read a 24 bit conversion result after a command.
+    pub(super) async fn read_data<S: SpiDevice, P>(spi: &mut S, command: u8) -> Result<i64, Error<S::Error, P>> {
+        let mut buf = [0_u8; 3];
+        spi.transaction(&mut [Operation::Write(&[command]), Operation::Read(&mut buf)]).await.map_err(Error::Spi)?;
+        Ok(FORMAT.from_be_bytes(&buf))
+    }
+}
+
+#[cfg(feature = "ads1220")]
+pub use ads1220::Ads1220;
+
+#[cfg(feature = "ads1220")]
+mod ads1220 {
+    use super::*;
+
+    const CMD_RESET: u8 = 0x06;
+    const CMD_START: u8 = 0x08;
+    const CMD_RDATA: u8 = 0x10;
+    /// WREG starting at register `rr` for `nn + 1` registers: 0100 rrnn.
+    const CMD_WREG: u8 = 0x40;
+
+    /// ADS1220 4 channel, 24 bit ADC.
+    #[derive(Debug)]
+    pub struct Ads1220<SPI, DRDY> {
+        spi: SPI,
+        drdy: DRDY,
+        r_ref: u32,
+        config: RtdConfig,
+    }
+
+    impl<SPI: SpiDevice, DRDY: InputPin> Ads1220<SPI, DRDY> {
+        /// Create a driver for a converter with the reference resistor `r_ref` in Ω.
+        ///
+        /// The converter is not configured until [`Ads1220::configure`] is called.
+        pub fn new(spi: SPI, drdy: DRDY, r_ref: u32) -> Self {
+            Ads1220 { spi, drdy, r_ref, config: UNCONFIGURED }
+        }
+
+        /// Release the SPI device and the DRDY pin.
+        pub fn release(self) -> (SPI, DRDY) {
+            (self.spi, self.drdy)
+        }
+
+        /// Reset the converter to its default configuration.
+        pub fn reset(&mut self, delay: &mut impl DelayNs) -> Result<(), Error<SPI::Error, DRDY::Error>> {
+            command(&mut self.spi, &[CMD_RESET])?;
+            delay.delay_us(100);
+            Ok(())
+        }
+
+        /// Write the configuration of the measurement to the converter.
+        pub fn configure(&mut self, config: RtdConfig) -> Result<(), Error<SPI::Error, DRDY::Error>> {
+            command(&mut self.spi, &registers(config)?)?;
+            self.config = config;
+            Ok(())
+        }
+    }
+
+    /// WREG command writing the configuration of the measurement to registers 0–3.
+    fn registers<S, P>(config: RtdConfig) -> Result<[u8; 5], Error<S, P>> {
+        let mux = match config.input {
+            (0, 1) => 0b0000,
+            (0, 2) => 0b0001,
+            (0, 3) => 0b0010,
+            (1, 2) => 0b0011,
+            (1, 3) => 0b0100,
+            (2, 3) => 0b0101,
+            (1, 0) => 0b0110,
+            (3, 2) => 0b0111,
+            _ => return Err(Error::InvalidConfig),
+        };
+        let vref = match config.reference {
+            Reference::Ref0 => 0b01,
+            Reference::Ref1 => 0b10,
+        };
+        let idac = match config.current {
+            IdacCurrent::UA10 => 0b001,
+            IdacCurrent::UA50 => 0b010,
+            IdacCurrent::UA100 => 0b011,
+            IdacCurrent::UA250 => 0b100,
+            IdacCurrent::UA500 => 0b101,
+            IdacCurrent::UA1000 => 0b110,
+            IdacCurrent::UA1500 => 0b111,
+        };
+        Ok([
+            CMD_WREG | 3,
+            mux << 4 | config.gain.bits() << 1,
+            // 20 SPS, normal mode, single-shot
+            0x00,
+            // simultaneous 50 Hz and 60 Hz rejection
+            vref << 6 | 0b01 << 4 | idac,
+            idac_mux(config.idac_1, config.idac_2)?,
+        ])
+    }
+
+    /// Value of register 3 routing the IDACs.
+    fn idac_mux<S, P>(idac_1: u8, idac_2: Option<u8>) -> Result<u8, Error<S, P>> {
+        let pin = |ain: u8| match ain {
+            0..=3 => Ok(ain + 1),
+            _ => Err(Error::InvalidConfig),
+        };
+        let i2mux = match idac_2 {
+            Some(ain) => pin(ain)?,
+            None => 0,
+        };
+        Ok(pin(idac_1)? << 5 | i2mux << 2)
+    }
+
+    /// WREG command routing the IDACs.
+    fn route<S, P>(idac_1: u8, idac_2: Option<u8>) -> Result<[u8; 2], Error<S, P>> {
+        Ok([CMD_WREG | 3 << 2, idac_mux(idac_1, idac_2)?])
+    }
+
+    impl<SPI: SpiDevice, DRDY: InputPin> RtdAdc for Ads1220<SPI, DRDY> {
+        type SpiError = SPI::Error;
+        type PinError = DRDY::Error;
+
+        fn config(&self) -> &RtdConfig {
+            &self.config
+        }
+
+        fn r_ref(&self) -> u32 {
+            self.r_ref
+        }
+
+        fn route_idacs(&mut self, idac_1: u8, idac_2: Option<u8>) -> Result<(), Error<SPI::Error, DRDY::Error>> {
+            command(&mut self.spi, &route(idac_1, idac_2)?)
+        }
+
+        fn convert(&mut self, delay: &mut impl DelayNs) -> Result<i64, Error<SPI::Error, DRDY::Error>> {
+            command(&mut self.spi, &[CMD_START])?;
+            wait_drdy(&mut self.drdy, delay)?;
+            read_data(&mut self.spi, CMD_RDATA)
+        }
+    }
+
+    /// Async variant of the driver, see [`super::asynch`].
+    #[cfg(feature = "async")]
+    pub mod asynch {
+        use embedded_hal_async::{
+            delay::DelayNs,
+            digital::Wait,
+            spi::SpiDevice,
+        };
+
+        use super::{
+            registers,
+            route,
+            Error,
+            RtdConfig,
+            CMD_RDATA,
+            CMD_RESET,
+            CMD_START,
+            UNCONFIGURED,
+        };
+        use crate::ads::asynch::{
+            command,
+            read_data,
+            wait_drdy,
+            RtdAdc,
+        };
+
+        /// ADS1220 4 channel, 24 bit ADC.
+        #[derive(Debug)]
+        pub struct Ads1220<SPI, DRDY> {
+            spi: SPI,
+            drdy: DRDY,
+            r_ref: u32,
+            config: RtdConfig,
+        }
+
+        impl<SPI: SpiDevice, DRDY: Wait> Ads1220<SPI, DRDY> {
+            /// Create a driver for a converter with the reference resistor `r_ref` in Ω.
+            ///
+            /// The converter is not configured until [`Ads1220::configure`] is called.
+            pub fn new(spi: SPI, drdy: DRDY, r_ref: u32) -> Self {
+                Ads1220 { spi, drdy, r_ref, config: UNCONFIGURED }
+            }
+
+            /// Release the SPI device and the DRDY pin.
+            pub fn release(self) -> (SPI, DRDY) {
+                (self.spi, self.drdy)
+            }
+
+            /// Reset the converter to its default configuration.
+            pub async fn reset(&mut self, delay: &mut impl DelayNs) -> Result<(), Error<SPI::Error, DRDY::Error>> {
+                command(&mut self.spi, &[CMD_RESET]).await?;
+                delay.delay_us(100).await;
+                Ok(())
+            }
+
+            /// Write the configuration of the measurement to the converter.
+            pub async fn configure(&mut self, config: RtdConfig) -> Result<(), Error<SPI::Error, DRDY::Error>> {
+                command(&mut self.spi, &registers(config)?).await?;
+                self.config = config;
+                Ok(())
+            }
+        }
+
+        impl<SPI: SpiDevice, DRDY: Wait> RtdAdc for Ads1220<SPI, DRDY> {
+            type SpiError = SPI::Error;
+            type PinError = DRDY::Error;
+
+            fn config(&self) -> &RtdConfig {
+                &self.config
+            }
+
+            fn r_ref(&self) -> u32 {
+                self.r_ref
+            }
+
+            async fn route_idacs(&mut self, idac_1: u8, idac_2: Option<u8>) -> Result<(), Error<SPI::Error, DRDY::Error>> {
+                command(&mut self.spi, &route(idac_1, idac_2)?).await
+            }
+
+            async fn convert(&mut self) -> Result<i64, Error<SPI::Error, DRDY::Error>> {
+                command(&mut self.spi, &[CMD_START]).await?;
+                wait_drdy(&mut self.drdy).await?;
+                read_data(&mut self.spi, CMD_RDATA).await
+            }
+        }
+    }
+}
+
+#[cfg(feature = "ads124s08")]
+pub use ads124s08::Ads124s08;
+
+#[cfg(feature = "ads124s08")]
+mod ads124s08 {
+    use super::*;
+
+    const CMD_RESET: u8 = 0x06;
+    const CMD_START: u8 = 0x08;
+    const CMD_RDATA: u8 = 0x12;
+    /// WREG starting at register `r rrrr`, followed by the number of registers minus one.
+    const CMD_WREG: u8 = 0x40;
+
+    const REG_INPMUX: u8 = 0x02;
+    const REG_IDACMUX: u8 = 0x07;
+    /// IDAC output disconnected.
+    const IDAC_OFF: u8 = 0x0F;
+
+    /// ADS124S08 12 channel, 24 bit ADC.
+    #[derive(Debug)]
+    pub struct Ads124s08<SPI, DRDY> {
+        spi: SPI,
+        drdy: DRDY,
+        r_ref: u32,
+        config: RtdConfig,
+    }
+
+    impl<SPI: SpiDevice, DRDY: InputPin> Ads124s08<SPI, DRDY> {
+        /// Create a driver for a converter with the reference resistor `r_ref` in Ω.
+        ///
+        /// The converter is not configured until [`Ads124s08::configure`] is called.
+        pub fn new(spi: SPI, drdy: DRDY, r_ref: u32) -> Self {
+            Ads124s08 { spi, drdy, r_ref, config: UNCONFIGURED }
+        }
+
+        /// Release the SPI device and the DRDY pin.
+        pub fn release(self) -> (SPI, DRDY) {
+            (self.spi, self.drdy)
+        }
+
+        /// Reset the converter to its default configuration.
+        pub fn reset(&mut self, delay: &mut impl DelayNs) -> Result<(), Error<SPI::Error, DRDY::Error>> {
+            command(&mut self.spi, &[CMD_RESET])?;
+            delay.delay_ms(1);
+            Ok(())
+        }
+
+        /// Write the configuration of the measurement to the converter.
+        pub fn configure(&mut self, config: RtdConfig) -> Result<(), Error<SPI::Error, DRDY::Error>> {
+            command(&mut self.spi, &registers(config)?)?;
+            self.config = config;
+            Ok(())
+        }
+    }
+
+    /// WREG command writing the configuration of the measurement to INPMUX–IDACMUX.
+    fn registers<S, P>(config: RtdConfig) -> Result<[u8; 8], Error<S, P>> {
+        let (p, n) = config.input;
+        if p > 12 || n > 12 {
+            return Err(Error::InvalidConfig);
+        }
+        let refsel = match config.reference {
+            Reference::Ref0 => 0b00,
+            Reference::Ref1 => 0b01,
+        };
+        let imag = match config.current {
+            IdacCurrent::UA10 => 0b0001,
+            IdacCurrent::UA50 => 0b0010,
+            IdacCurrent::UA100 => 0b0011,
+            IdacCurrent::UA250 => 0b0100,
+            IdacCurrent::UA500 => 0b0101,
+            IdacCurrent::UA1000 => 0b0111,
+            IdacCurrent::UA1500 => 0b1000,
+        };
+        Ok([
+            CMD_WREG | REG_INPMUX,
+            REG_IDACMUX - REG_INPMUX,
+            p << 4 | n,
+            // PGA enabled
+            0b01 << 3 | config.gain.bits(),
+            // single-shot, low-latency filter, 20 SPS
+            0b0011_0100,
+            // negative reference buffer disabled, internal reference on for the IDACs
+            0b0001_0010 | refsel << 2,
+            imag,
+            idac_mux(config.idac_1, config.idac_2)?,
+        ])
+    }
+
+    /// Value of the IDACMUX register.
+    fn idac_mux<S, P>(idac_1: u8, idac_2: Option<u8>) -> Result<u8, Error<S, P>> {
+        let pin = |ain: u8| match ain {
+            0..=12 => Ok(ain),
+            _ => Err(Error::InvalidConfig),
+        };
+        let i2mux = match idac_2 {
+            Some(ain) => pin(ain)?,
+            None => IDAC_OFF,
+        };
+        Ok(i2mux << 4 | pin(idac_1)?)
+    }
+
+    /// WREG command routing the IDACs.
+    fn route<S, P>(idac_1: u8, idac_2: Option<u8>) -> Result<[u8; 3], Error<S, P>> {
+        Ok([CMD_WREG | REG_IDACMUX, 0, idac_mux(idac_1, idac_2)?])
+    }
+
+    impl<SPI: SpiDevice, DRDY: InputPin> RtdAdc for Ads124s08<SPI, DRDY> {
+        type SpiError = SPI::Error;
+        type PinError = DRDY::Error;
+
+        fn config(&self) -> &RtdConfig {
+            &self.config
+        }
+
+        fn r_ref(&self) -> u32 {
+            self.r_ref
+        }
+
+        fn route_idacs(&mut self, idac_1: u8, idac_2: Option<u8>) -> Result<(), Error<SPI::Error, DRDY::Error>> {
+            command(&mut self.spi, &route(idac_1, idac_2)?)
+        }
+
+        fn convert(&mut self, delay: &mut impl DelayNs) -> Result<i64, Error<SPI::Error, DRDY::Error>> {
+            command(&mut self.spi, &[CMD_START])?;
+            wait_drdy(&mut self.drdy, delay)?;
+            read_data(&mut self.spi, CMD_RDATA)
+        }
+    }
+
+    /// Async variant of the driver, see [`super::asynch`].
+    #[cfg(feature = "async")]
+    pub mod asynch {
+        use embedded_hal_async::{
+            delay::DelayNs,
+            digital::Wait,
+            spi::SpiDevice,
+        };
+
+        use super::{
+            registers,
+            route,
+            Error,
+            RtdConfig,
+            CMD_RDATA,
+            CMD_RESET,
+            CMD_START,
+            UNCONFIGURED,
+        };
+        use crate::ads::asynch::{
+            command,
+            read_data,
+            wait_drdy,
+            RtdAdc,
+        };
+
+        /// ADS124S08 12 channel, 24 bit ADC.
+        #[derive(Debug)]
+        pub struct Ads124s08<SPI, DRDY> {
+            spi: SPI,
+            drdy: DRDY,
+            r_ref: u32,
+            config: RtdConfig,
+        }
+
+        impl<SPI: SpiDevice, DRDY: Wait> Ads124s08<SPI, DRDY> {
+            /// Create a driver for a converter with the reference resistor `r_ref` in Ω.
+            ///
+            /// The converter is not configured until [`Ads124s08::configure`] is called.
+            pub fn new(spi: SPI, drdy: DRDY, r_ref: u32) -> Self {
+                Ads124s08 { spi, drdy, r_ref, config: UNCONFIGURED }
+            }
+
+            /// Release the SPI device and the DRDY pin.
+            pub fn release(self) -> (SPI, DRDY) {
+                (self.spi, self.drdy)
+            }
+
+            /// Reset the converter to its default configuration.
+            pub async fn reset(&mut self, delay: &mut impl DelayNs) -> Result<(), Error<SPI::Error, DRDY::Error>> {
+                command(&mut self.spi, &[CMD_RESET]).await?;
+                delay.delay_ms(1).await;
+                Ok(())
+            }
+
+            /// Write the configuration of the measurement to the converter.
+            pub async fn configure(&mut self, config: RtdConfig) -> Result<(), Error<SPI::Error, DRDY::Error>> {
+                command(&mut self.spi, &registers(config)?).await?;
+                self.config = config;
+                Ok(())
+            }
+        }
+
+        impl<SPI: SpiDevice, DRDY: Wait> RtdAdc for Ads124s08<SPI, DRDY> {
+            type SpiError = SPI::Error;
+            type PinError = DRDY::Error;
+
+            fn config(&self) -> &RtdConfig {
+                &self.config
+            }
+
+            fn r_ref(&self) -> u32 {
+                self.r_ref
+            }
+
+            async fn route_idacs(&mut self, idac_1: u8, idac_2: Option<u8>) -> Result<(), Error<SPI::Error, DRDY::Error>> {
+                command(&mut self.spi, &route(idac_1, idac_2)?).await
+            }
+
+            async fn convert(&mut self) -> Result<i64, Error<SPI::Error, DRDY::Error>> {
+                command(&mut self.spi, &[CMD_START]).await?;
+                wait_drdy(&mut self.drdy).await?;
+                read_data(&mut self.spi, CMD_RDATA).await
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    extern crate std;
+
+    use super::*;
+    use crate::RTDType;
+    use embedded_hal_mock::eh1::{
+        delay::NoopDelay,
+        digital::{
+            Mock as PinMock,
+            State,
+            Transaction as PinTransaction,
+        },
+        spi::{
+            Mock as SpiMock,
+            Transaction,
+        },
+    };
+    use libm::fabsf;
+    use std::vec;
+
+    /// Expected transactions of a command.
+    fn command(bytes: &[u8]) -> [Transaction<u8>; 3] {
+        [Transaction::transaction_start(), Transaction::write_vec(bytes.to_vec()), Transaction::transaction_end()]
+    }
+
+    /// Expected transactions of reading a conversion result.
+    fn read_data(command: u8, data: &[u8]) -> [Transaction<u8>; 4] {
+        [
+            Transaction::transaction_start(),
+            Transaction::write_vec(vec![command]),
+            Transaction::read_vec(data.to_vec()),
+            Transaction::transaction_end(),
+        ]
+    }
+
+    #[cfg(feature = "ads1220")]
+    #[test]
+    fn ads1220_three_wire_chopping() {
+        // PT100 at 100°C, 1 Ω leads, IDACs mismatched by ±0.2 %
+        let expectations = [
+            &command(&[0x43, 0x38, 0x00, 0x55, 0x30])[..],
+            &command(&[0x08]),
+            &read_data(0x10, &[0x57, 0xBA, 0x0E]),
+            &command(&[0x4C, 0x84]),
+            &command(&[0x08]),
+            &read_data(0x10, &[0x57, 0x5F, 0x1D]),
+            &command(&[0x4C, 0x30]),
+        ].concat();
+        let drdy = [PinTransaction::get(State::High), PinTransaction::get(State::Low), PinTransaction::get(State::Low)];
+        let mut adc = Ads1220::new(SpiMock::new(&expectations), PinMock::new(&drdy), 1620);
+        adc.configure(RtdConfig {
+            input: (1, 2),
+            idac_1: 0,
+            idac_2: Some(3),
+            current: IdacCurrent::UA500,
+            gain: Gain::X16,
+            reference: Reference::Ref0,
+        }).unwrap();
+        let t = adc.read_temperature(&RTDType::PT100, &mut NoopDelay::new()).unwrap();
+        assert!(fabsf(t - 100_f32) < 1e-3);
+
+        assert!(matches!(adc.configure(RtdConfig { input: (2, 1), ..*adc.config() }), Err(Error::InvalidConfig)));
+        let (mut spi, mut drdy) = adc.release();
+        spi.done();
+        drdy.done();
+    }
+
+    #[cfg(feature = "ads124s08")]
+    #[test]
+    fn ads124s08_two_wire() {
+        // PT1000 at 0°C with a 4020 Ω reference
+        let expectations = [
+            &command(&[0x42, 0x05, 0x23, 0x0A, 0x34, 0x16, 0x07, 0xF1])[..],
+            &command(&[0x08]),
+            &read_data(0x12, &[0x7F, 0x5C, 0xFA]),
+        ].concat();
+        let drdy = [PinTransaction::get(State::Low)];
+        let mut adc = Ads124s08::new(SpiMock::new(&expectations), PinMock::new(&drdy), 4020);
+        adc.configure(RtdConfig {
+            input: (2, 3),
+            idac_1: 1,
+            idac_2: None,
+            current: IdacCurrent::UA1000,
+            gain: Gain::X4,
+            reference: Reference::Ref1,
+        }).unwrap();
+        let t = adc.read_temperature(&RTDType::PT1000, &mut NoopDelay::new()).unwrap();
+        assert!(fabsf(t) < 1e-3);
+
+        assert!(matches!(adc.configure(RtdConfig { input: (13, 0), ..*adc.config() }), Err(Error::InvalidConfig)));
+        let (mut spi, mut drdy) = adc.release();
+        spi.done();
+        drdy.done();
+    }
+
+    #[cfg(all(feature = "ads1220", feature = "async"))]
+    #[test]
+    fn ads1220_async_three_wire_chopping() {
+        use asynch::RtdAdc;
+        use crate::executor::block_on;
+
+        // same measurement as the blocking driver, DRDY is awaited once per conversion
+        let expectations = [
+            &command(&[0x43, 0x38, 0x00, 0x55, 0x30])[..],
+            &command(&[0x08]),
+            &read_data(0x10, &[0x57, 0xBA, 0x0E]),
+            &command(&[0x4C, 0x84]),
+            &command(&[0x08]),
+            &read_data(0x10, &[0x57, 0x5F, 0x1D]),
+            &command(&[0x4C, 0x30]),
+        ].concat();
+        let drdy = [PinTransaction::wait_for_state(State::Low), PinTransaction::wait_for_state(State::Low)];
+        let mut adc = asynch::Ads1220::new(SpiMock::new(&expectations), PinMock::new(&drdy), 1620);
+        let t = block_on(async {
+            adc.configure(RtdConfig {
+                input: (1, 2),
+                idac_1: 0,
+                idac_2: Some(3),
+                current: IdacCurrent::UA500,
+                gain: Gain::X16,
+                reference: Reference::Ref0,
+            }).await.unwrap();
+            adc.read_temperature(&RTDType::PT100).await.unwrap()
+        });
+        assert!(fabsf(t - 100_f32) < 1e-3);
+        let (mut spi, mut drdy) = adc.release();
+        spi.done();
+        drdy.done();
+    }
+
+    #[cfg(all(feature = "ads124s08", feature = "async"))]
+    #[test]
+    fn ads124s08_async_two_wire() {
+        use asynch::RtdAdc;
+        use crate::executor::block_on;
+
+        let expectations = [
+            &command(&[0x06])[..],
+            &command(&[0x42, 0x05, 0x23, 0x0A, 0x34, 0x16, 0x07, 0xF1]),
+            &command(&[0x08]),
+            &read_data(0x12, &[0x7F, 0x5C, 0xFA]),
+        ].concat();
+        let drdy = [PinTransaction::wait_for_state(State::Low)];
+        let mut adc = asynch::Ads124s08::new(SpiMock::new(&expectations), PinMock::new(&drdy), 4020);
+        let r = block_on(async {
+            adc.reset(&mut NoopDelay::new()).await.unwrap();
+            adc.configure(RtdConfig {
+                input: (2, 3),
+                idac_1: 1,
+                idac_2: None,
+                current: IdacCurrent::UA1000,
+                gain: Gain::X4,
+                reference: Reference::Ref1,
+            }).await.unwrap();
+            adc.read_resistance().await.unwrap()
+        });
+        assert!(fabsf(r - 1000_f32) < 1e-2);
+        let (mut spi, mut drdy) = adc.release();
+        spi.done();
+        drdy.done();
+    }
+}
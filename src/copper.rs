@@ -0,0 +1,136 @@
+//! Calculation methods for copper type RTD temperature sensors.
+//!
+//! The functions mirror [`calc_r`](crate::calc_r) and [`calc_t`](crate::calc_t) for platinum
+//! sensors. Resistance is calculated according to GOST 6651-2009 (α = 0.00428) from
+//!
+//! R(t) = R0 · (1 + A·t) for t >= 0°C,
+//! R(t) = R0 · (1 + A·t + B·t·(t + 6.7) + C·t³) for t < 0°C.
+//!
+//! Temperature is found by Newton–Raphson iteration.
+
+use libm::powf;
+
+use crate::{
+    newton_raphson,
+    Error,
+    Quantity,
+    ResistiveSensor,
+};
+
+const A: f32 = 4.28e-3;
+const B: f32 = -6.2032e-7;
+const C: f32 = 8.5154e-10;
+
+/// Copper RTD of arbitrary nominal resistance R0 at 0°C.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct CuType {
+    r_0: f64,
+}
+
+impl CuType {
+    pub const CU10: CuType = CuType::new(10_f64);
+    pub const CU50: CuType = CuType::new(50_f64);
+    pub const CU100: CuType = CuType::new(100_f64);
+
+    /// Create a sensor type with nominal resistance `r_0` at 0°C in Ω.
+    pub const fn new(r_0: f64) -> Self {
+        CuType { r_0 }
+    }
+
+    /// Nominal resistance at 0°C in Ω.
+    pub const fn r_0(&self) -> f64 {
+        self.r_0
+    }
+
+    /// Evaluate the curve without range checks.
+    fn r(&self, t: f32) -> f32 {
+        let r_0 = self.r_0 as f32;
+        match t {
+            t if t >= 0_f32 => r_0 * ( 1_f32 + A * t ),
+            t => r_0 * ( 1_f32 + A * t + B * t * ( t + 6.7 ) + C * powf(t, 3_f32) ),
+        }
+    }
+
+    /// Evaluate the derivative dR/dt of the curve.
+    fn dr_dt(&self, t: f32) -> f32 {
+        let r_0 = self.r_0 as f32;
+        match t {
+            t if t >= 0_f32 => r_0 * A,
+            t => r_0 * ( A + B * ( 2_f32 * t + 6.7 ) + 3_f32 * C * powf(t, 2_f32) ),
+        }
+    }
+}
+
+/// Calculate resistance of copper RTD for a specified temperature.
+///
+/// Allowed temperature range: -180–200°C.
+#[allow(dead_code)]
+pub fn calc_r(t: f32, r_0: CuType) -> Result<f32, Error> {
+    if !(r_0.r_0.is_finite() && r_0.r_0 > 0_f64) {
+        return Err(Error::NonexistentType);
+    }
+    match t {
+        t if (-180_f32..=200_f32).contains(&t) => Ok(r_0.r(t)),
+        t => Err(Error::out_of_range(Quantity::Temperature, t as f64, -180_f64, 200_f64)),
+    }
+}
+
+/// Calculate temperature of copper RTD from resistance value.
+///
+/// The result is solved to within 1 ppm of R0. Allowed temperature range: -180–200°C.
+#[allow(dead_code)]
+pub fn calc_t(r: f32, r_0: CuType) -> Result<f32, Error> {
+    let r_min = calc_r(-180_f32, r_0)?;
+    let r_max = calc_r(200_f32, r_0)?;
+    if !(r_min..=r_max).contains(&r) {
+        return Err(Error::out_of_range(Quantity::Resistance, r as f64, r_min as f64, r_max as f64));
+    }
+
+    // start from the linear equation, which is exact for t >= 0°C
+    let t = ( r / r_0.r_0 as f32 - 1_f32 ) / A;
+    let tolerance = r_0.r_0 as f32 * 1e-6;
+    newton_raphson(r, t, tolerance, |t| r_0.r(t), |t| r_0.dr_dt(t)).map(|solution| solution.t)
+}
+
+impl ResistiveSensor for CuType {
+    fn resistance_at(&self, t: f32) -> Result<f32, Error> {
+        calc_r(t, *self)
+    }
+
+    fn temperature_at(&self, r: f32) -> Result<f32, Error> {
+        calc_t(r, *self)
+    }
+
+    fn valid_range(&self) -> (f32, f32) {
+        (-180_f32, 200_f32)
+    }
+
+    fn sensitivity_at(&self, t: f32) -> Result<f32, Error> {
+        calc_r(t, *self)?;
+        Ok(self.dr_dt(t))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use libm::fabsf;
+
+    #[test]
+    fn resistance_calculation() {
+        // GOST 6651-2009 reference values for Cu100
+        assert!(fabsf(calc_r(100_f32, CuType::CU100).unwrap() - 142.8) < 1e-3);
+        assert!(fabsf(calc_r(-50_f32, CuType::CU100).unwrap() - 78.46) < 0.01);
+        assert!(matches!(calc_r(-200_f32, CuType::CU10), Err(Error::OutOfRange { .. })));
+    }
+
+    #[test]
+    fn temperature_calculation() {
+        for sensor in [CuType::CU10, CuType::CU50, CuType::CU100] {
+            for t in (-180..=200).step_by(5) {
+                let r = calc_r(t as f32, sensor).unwrap();
+                assert!(fabsf(calc_t(r, sensor).unwrap() - t as f32) < 1e-3);
+            }
+        }
+    }
+}
@@ -0,0 +1,24 @@
+//! Minimal executor to run the async drivers on the host in tests.
+
+use core::{
+    future::Future,
+    pin::pin,
+    task::{
+        Context,
+        Poll,
+        Waker,
+    },
+};
+
+/// Poll a future to completion on the current thread.
+///
+/// The mocks complete immediately, so the future is polled again without waiting for a wake-up.
+pub fn block_on<F: Future>(future: F) -> F::Output {
+    let mut future = pin!(future);
+    let mut cx = Context::from_waker(Waker::noop());
+    loop {
+        if let Poll::Ready(output) = future.as_mut().poll(&mut cx) {
+            return output;
+        }
+    }
+}
@@ -0,0 +1,223 @@
+//! Diagnosis of sensor and wiring faults from the raw ADC codes.
+//!
+//! A fault usually drives the converter to one of its rails, which the conversion functions only
+//! report as [`Error::AdcOverrange`] or [`Error::OutOfRange`]. [`diagnose`] classifies the codes of
+//! a ratiometric measurement instead, where the excitation current flows through the leads, the
+//! element and the reference resistor to ground:
+//!
+//! | Element reading                                               | Fault                    |
+//! |---------------------------------------------------------------|--------------------------|
+//! | positive rail                                                 | [`Fault::OpenSensor`]    |
+//! | negative rail (bipolar converters)                            | [`Fault::BrokenLead`]    |
+//! | below half the resistance at the lowest temperature           | [`Fault::ShortedSensor`] |
+//! | lead reading at a rail or above the element reading (3-wire)  | [`Fault::BrokenLead`]    |
+//!
+//! A short to the supply or to ground and a fault of the reference resistor cannot be told from
+//! the element reading alone. They are detected with [`Diagnostics`], single-ended readings of the
+//! excitation circuit against the supply of the current source, as used for burn-out detection:
+//!
+//! | Excitation input | Reference resistor | Fault                                   |
+//! |------------------|--------------------|-----------------------------------------|
+//! | ≥ 98 %           | any                | [`Fault::ShortToSupply`]                |
+//! | any              | ≥ 98 %             | [`Fault::ShortToSupply`]                |
+//! | ≤ 5 %            | any                | [`Fault::ShortToGround`]                |
+//! | any              | ≥ 85 %             | [`Fault::ReferenceFault`], open         |
+//! | ≥ 85 %           | ≤ 5 %              | [`Fault::OpenSensor`], no current flows |
+//! | < 85 %           | ≤ 5 %              | [`Fault::ReferenceFault`], shorted      |
+//!
+//! If the excitation current flows, a rail of the element reading is caused by an open sense
+//! lead and reported as [`Fault::BrokenLead`]. A reference resistor shorted and the low side of the
+//! sensor shorted to ground are electrically the same.
+
+use core::fmt;
+
+use crate::{
+    adc::{
+        AdcFormat,
+        Coding,
+    },
+    wiring::Wiring,
+    Error,
+    ResistiveSensor,
+};
+
+/// Node at the supply, above the compliance of any current source.
+const SUPPLY: f32 = 0.98;
+/// Node at the compliance of the current source, as used by the MAX31865.
+const COMPLIANCE: f32 = 0.85;
+/// Node at ground.
+const GROUND: f32 = 0.05;
+
+/// Fault of the sensor or its wiring.
+#[non_exhaustive]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Fault {
+    /// The element or a lead carrying the excitation current is open, e.g. an unplugged probe.
+    OpenSensor,
+    /// The element is shorted.
+    ShortedSensor,
+    /// A lead without excitation current is open, e.g. a sense lead of a four-wire connection.
+    BrokenLead,
+    /// The sensor or a lead is shorted to the supply.
+    ShortToSupply,
+    /// The excitation input of the sensor is shorted to ground.
+    ShortToGround,
+    /// The reference resistor is open or shorted.
+    ReferenceFault,
+}
+
+impl fmt::Display for Fault {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Fault::OpenSensor => write!(f, "open sensor"),
+            Fault::ShortedSensor => write!(f, "shorted sensor"),
+            Fault::BrokenLead => write!(f, "broken lead"),
+            Fault::ShortToSupply => write!(f, "short to supply"),
+            Fault::ShortToGround => write!(f, "short to ground"),
+            Fault::ReferenceFault => write!(f, "reference resistor fault"),
+        }
+    }
+}
+
+/// Single-ended readings of the excitation circuit, measured with the supply of the current source
+/// as reference and without PGA gain, in the same format as the element reading.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Diagnostics {
+    /// Code of the excitation input of the sensor, i.e. the output of the current source.
+    pub d_val_exc: i64,
+    /// Code of the high side of the reference resistor.
+    pub d_val_ref: i64,
+}
+
+/// Diagnose the codes of a ratiometric measurement, returns `None` if no fault is found.
+///
+/// `d_val` is the reading across the element with the reference `r_ref` in Ω, as for
+/// [`adc::conv_d_val_to_r`](crate::adc::conv_d_val_to_r). Without `diagnostics` only the faults
+/// visible in the element and lead readings are detected.
+#[allow(dead_code)]
+pub fn diagnose(
+    d_val: i64,
+    r_ref: u32,
+    format: AdcFormat,
+    pga_gain: u32,
+    wiring: Wiring,
+    diagnostics: Option<Diagnostics>,
+    sensor: &impl ResistiveSensor,
+) -> Result<Option<Fault>, Error> {
+    if pga_gain == 0 {
+        return Err(Error::ZeroGain);
+    }
+    if let Some(Diagnostics { d_val_exc, d_val_ref }) = diagnostics {
+        let (exc, refr) = (ratio(d_val_exc, format), ratio(d_val_ref, format));
+        let fault = match (exc, refr) {
+            (exc, refr) if exc >= SUPPLY || refr >= SUPPLY => Some(Fault::ShortToSupply),
+            (exc, _) if exc <= GROUND => Some(Fault::ShortToGround),
+            (_, refr) if refr >= COMPLIANCE => Some(Fault::ReferenceFault),
+            (exc, refr) if refr <= GROUND && exc >= COMPLIANCE => Some(Fault::OpenSensor),
+            (_, refr) if refr <= GROUND => Some(Fault::ReferenceFault),
+            // the excitation current flows, so a rail is caused by an open sense lead
+            _ if d_val >= format.max() || ( d_val <= format.min() && format.coding() == Coding::Bipolar ) => {
+                Some(Fault::BrokenLead)
+            },
+            _ => None,
+        };
+        if fault.is_some() {
+            return Ok(fault);
+        }
+    }
+    match (d_val, format.coding()) {
+        (d, _) if d >= format.max() => return Ok(Some(Fault::OpenSensor)),
+        (d, Coding::Bipolar) if d <= format.min() => return Ok(Some(Fault::BrokenLead)),
+        _ => {},
+    }
+
+    let r = |d_val: i64| ratio(d_val, format) * r_ref as f32 / pga_gain as f32;
+    let r_leads = match wiring {
+        Wiring::TwoWire { r_leads } => r_leads,
+        Wiring::ThreeWire { d_val_leads } => match d_val_leads {
+            d if d >= format.max() || d > d_val => return Ok(Some(Fault::BrokenLead)),
+            d if d <= format.min() && format.coding() == Coding::Bipolar => return Ok(Some(Fault::BrokenLead)),
+            d => r(d),
+        },
+        Wiring::FourWire => 0_f32,
+    };
+    let (t_min, _) = sensor.valid_range();
+    match r(d_val) - r_leads {
+        r if r < sensor.resistance_at(t_min)? / 2_f32 => Ok(Some(Fault::ShortedSensor)),
+        _ => Ok(None),
+    }
+}
+
+/// Ratio of a code to the full scale of the converter.
+fn ratio(d_val: i64, format: AdcFormat) -> f32 {
+    ( d_val as f64 / format.full_scale() as f64 ) as f32
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::RTDType;
+
+    const FORMAT: AdcFormat = AdcFormat::bipolar(24);
+
+    /// Code of a 24 bit bipolar ADC with a 400 Ω reference for a resistance.
+    fn d_val(r: f32) -> i64 {
+        ( r as f64 / 400_f64 * FORMAT.full_scale() as f64 ) as i64
+    }
+
+    /// Diagnostic readings for fractions of the supply.
+    fn diagnostics(exc: f64, refr: f64) -> Option<Diagnostics> {
+        let d = |x: f64| ( x * FORMAT.full_scale() as f64 ) as i64;
+        Some(Diagnostics { d_val_exc: d(exc), d_val_ref: d(refr) })
+    }
+
+    #[test]
+    fn element_and_lead_readings() {
+        let sensor = RTDType::PT100;
+        let diagnose = |d_val, wiring| diagnose(d_val, 400, FORMAT, 1, wiring, None, &sensor).unwrap();
+        let three_wire = |r_leads| Wiring::ThreeWire { d_val_leads: d_val(r_leads) };
+
+        assert_eq!(diagnose(d_val(138.5), Wiring::FourWire), None);
+        assert_eq!(diagnose(d_val(140.5), three_wire(2_f32)), None);
+        assert_eq!(diagnose(FORMAT.max(), Wiring::TwoWire { r_leads: 0.5 }), Some(Fault::OpenSensor));
+        assert_eq!(diagnose(FORMAT.min(), Wiring::FourWire), Some(Fault::BrokenLead));
+        assert_eq!(diagnose(d_val(2.1), three_wire(2_f32)), Some(Fault::ShortedSensor));
+        assert_eq!(diagnose(d_val(1.2), Wiring::TwoWire { r_leads: 1_f32 }), Some(Fault::ShortedSensor));
+        assert_eq!(
+            diagnose(d_val(140.5), Wiring::ThreeWire { d_val_leads: FORMAT.max() }),
+            Some(Fault::BrokenLead),
+        );
+        assert_eq!(diagnose(d_val(140.5), three_wire(200_f32)), Some(Fault::BrokenLead));
+        // the lead reading of a bipolar converter may be negative, e.g. with an offset
+        assert_eq!(diagnose(d_val(138.5), Wiring::ThreeWire { d_val_leads: -10 }), None);
+        assert_eq!(diagnose(d_val(138.5), Wiring::ThreeWire { d_val_leads: FORMAT.min() }), Some(Fault::BrokenLead));
+
+        // a PT100 at -200°C is in range, not shorted
+        assert_eq!(diagnose(d_val(18.6), Wiring::FourWire), None);
+        assert!(matches!(
+            super::diagnose(0, 400, FORMAT, 0, Wiring::FourWire, None, &sensor),
+            Err(Error::ZeroGain),
+        ));
+    }
+
+    #[test]
+    fn excitation_circuit() {
+        // 1 mA through a PT100 at 100°C and the reference, 3.3 V supply
+        let sensor = RTDType::PT100;
+        let diagnose = |d_val, diagnostics| {
+            diagnose(d_val, 400, FORMAT, 1, Wiring::FourWire, diagnostics, &sensor).unwrap()
+        };
+        let r = d_val(138.5);
+
+        assert_eq!(diagnose(r, diagnostics(0.16, 0.12)), None);
+        assert_eq!(diagnose(r, diagnostics(0.99, 0.12)), Some(Fault::ShortToSupply));
+        assert_eq!(diagnose(r, diagnostics(0.01, 0_f64)), Some(Fault::ShortToGround));
+        assert_eq!(diagnose(0, diagnostics(0.88, 0.88)), Some(Fault::ReferenceFault));
+        assert_eq!(diagnose(FORMAT.max(), diagnostics(0.88, 0_f64)), Some(Fault::OpenSensor));
+        assert_eq!(diagnose(FORMAT.max(), diagnostics(0.04, 0_f64)), Some(Fault::ShortToGround));
+        assert_eq!(diagnose(FORMAT.max(), diagnostics(0.3, 0_f64)), Some(Fault::ReferenceFault));
+        // current flows, but the sense inputs are at a rail
+        assert_eq!(diagnose(FORMAT.max(), diagnostics(0.16, 0.12)), Some(Fault::BrokenLead));
+        assert_eq!(diagnose(FORMAT.min(), diagnostics(0.16, 0.12)), Some(Fault::BrokenLead));
+    }
+}
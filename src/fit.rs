@@ -0,0 +1,173 @@
+//! Least-squares fit of Callendar–Van Dusen coefficients from calibration points.
+//!
+//! The Callendar–Van Dusen equation is linear in R0, R0·A, R0·B and R0·C, so the coefficients are
+//! found by solving the normal equations of an ordinary least-squares problem. C only affects
+//! temperatures below 0°C and is therefore only fitted if at least one calibration point lies
+//! below 0°C and there are enough points to determine all four coefficients. Otherwise the
+//! standard value of DIN EN 60751 is kept for C.
+
+use libm::sqrt;
+
+use crate::{
+    CvdCoefficients,
+    Error,
+    C,
+};
+
+/// Result of a least-squares fit of Callendar–Van Dusen coefficients.
+#[derive(Debug, Clone, Copy)]
+pub struct CvdFit<const N: usize> {
+    /// Fitted coefficients.
+    pub coefficients: CvdCoefficients,
+    /// Whether C was fitted or set to the standard value.
+    pub c_fitted: bool,
+    /// Difference between measured and fitted resistance for each calibration point in Ω.
+    pub residuals: [f32; N],
+    /// Root mean square of the residuals in Ω.
+    pub rms: f32,
+}
+
+/// Fit R0, A, B and (if possible) C to calibration points given as `(temperature, resistance)`
+/// pairs in °C and Ω.
+///
+/// At least three points are needed, four if C is to be fitted.
+#[allow(dead_code)]
+pub fn fit_cvd<const N: usize>(points: &[(f32, f32); N]) -> Result<CvdFit<N>, Error> {
+    let sub_zero = points.iter().filter(|(t, _)| *t < 0_f32).count();
+    let n_params = match (sub_zero, N) {
+        (0, _) | (_, 0..=3) => 3,
+        _ => 4,
+    };
+    if N < n_params {
+        return Err(Error::InsufficientData);
+    }
+
+    // set up the normal equations for the parameters R0, R0·A, R0·B and R0·C
+    let mut ata = [[0_f64; 4]; 4];
+    let mut atb = [0_f64; 4];
+    for &(t, r) in points.iter() {
+        let row = basis(t as f64, n_params);
+        for i in 0..n_params {
+            for j in 0..n_params {
+                ata[i][j] += row[i] * row[j];
+            }
+            atb[i] += row[i] * r as f64;
+        }
+    }
+    let p = solve(ata, atb, n_params)?;
+
+    // undo the scaling of the temperature in the basis functions
+    let r_0 = p[0];
+    let coefficients = CvdCoefficients::new(
+        r_0,
+        p[1] / r_0 / 1e2,
+        p[2] / r_0 / 1e4,
+        match n_params {
+            4 => p[3] / r_0 / 1e8,
+            _ => C,
+        },
+    );
+
+    let mut residuals = [0_f32; N];
+    let mut sum_sq = 0_f64;
+    for (residual, &(t, r)) in residuals.iter_mut().zip(points.iter()) {
+        let row = basis(t as f64, n_params);
+        let fitted: f64 = row.iter().zip(p.iter()).map(|(x, p)| x * p).sum();
+        *residual = (r as f64 - fitted) as f32;
+        sum_sq += (r as f64 - fitted) * (r as f64 - fitted);
+    }
+
+    Ok(CvdFit {
+        coefficients,
+        c_fitted: n_params == 4,
+        residuals,
+        rms: sqrt(sum_sq / N as f64) as f32,
+    })
+}
+
+/// Basis functions of the Callendar–Van Dusen equation for temperature `t` in °C.
+///
+/// The temperature is scaled by 1/100 to keep the normal equations well conditioned. If C is not
+/// fitted, its standard value is folded into the R0 term.
+fn basis(t: f64, n_params: usize) -> [f64; 4] {
+    let x = t / 1e2;
+    let c_term = match t {
+        t if t < 0_f64 => (x - 1_f64) * x * x * x,
+        _ => 0_f64,
+    };
+    match n_params {
+        4 => [1_f64, x, x * x, c_term],
+        _ => [1_f64 + C * 1e8 * c_term, x, x * x, 0_f64],
+    }
+}
+
+/// Solve the linear system `a · x = b` of size `n` by Gaussian elimination with partial pivoting.
+fn solve(mut a: [[f64; 4]; 4], mut b: [f64; 4], n: usize) -> Result<[f64; 4], Error> {
+    for col in 0..n {
+        let pivot = (col..n)
+            .max_by(|&i, &j| a[i][col].abs().total_cmp(&a[j][col].abs()))
+            .unwrap_or(col);
+        if a[pivot][col].abs() < 1e-12 {
+            return Err(Error::InsufficientData); // points do not determine all coefficients
+        }
+        a.swap(col, pivot);
+        b.swap(col, pivot);
+        let pivot_row = a[col];
+        for row in col + 1..n {
+            let factor = a[row][col] / pivot_row[col];
+            for (x, p) in a[row][col..n].iter_mut().zip(pivot_row[col..n].iter()) {
+                *x -= factor * p;
+            }
+            b[row] -= factor * b[col];
+        }
+    }
+
+    let mut x = [0_f64; 4];
+    for row in (0..n).rev() {
+        let sum: f64 = (row + 1..n).map(|k| a[row][k] * x[k]).sum();
+        x[row] = (b[row] - sum) / a[row][row];
+    }
+    Ok(x)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use libm::fabsf;
+
+    #[test]
+    fn fit_above_zero() {
+        let sensor = CvdCoefficients::new(100.02, 3.9090e-3, -5.7800e-7, C);
+        let points = [0_f32, 100.0, 200.0, 300.0, 420.0].map(|t| (t, sensor.calc_r(t).unwrap()));
+
+        let fit = fit_cvd(&points).unwrap();
+        assert!(!fit.c_fitted);
+        assert!(( fit.coefficients.r_0 - sensor.r_0 ).abs() < 1e-4);
+        assert!(( fit.coefficients.a - sensor.a ).abs() / sensor.a < 1e-4);
+        assert!(( fit.coefficients.b - sensor.b ).abs() / sensor.b.abs() < 1e-2);
+        assert!(fit.rms < 1e-3);
+    }
+
+    #[test]
+    fn fit_with_sub_zero_points() {
+        let sensor = CvdCoefficients::new(99.98, 3.9075e-3, -5.7700e-7, -4.2000e-12);
+        let points = [-196_f32, -80.0, -40.0, 0.0, 100.0, 230.0].map(|t| (t, sensor.calc_r(t).unwrap()));
+
+        let fit = fit_cvd(&points).unwrap();
+        assert!(fit.c_fitted);
+        assert!(( fit.coefficients.c - sensor.c ).abs() / sensor.c.abs() < 1e-2);
+        for (t, r) in points {
+            assert!(fabsf(fit.coefficients.calc_t(r).unwrap() - t) < 2e-3);
+        }
+        assert!(fit.residuals.iter().all(|residual| fabsf(*residual) < 1e-3));
+    }
+
+    #[test]
+    fn fit_insufficient_data() {
+        assert!(matches!(fit_cvd(&[(0_f32, 100_f32), (100.0, 138.5)]), Err(Error::InsufficientData)));
+        assert!(matches!(
+            fit_cvd(&[(0_f32, 100_f32), (0.0, 100.0), (100.0, 138.5)]),
+            Err(Error::InsufficientData),
+        ));
+    }
+}
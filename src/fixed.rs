@@ -0,0 +1,213 @@
+//! Integer-only temperature conversion for microcontrollers without FPU.
+//!
+//! The resistance ratio W = R(t)/R0 of each curve is tabulated in Q29 format every 5°C over the
+//! full range of -200–850°C. The table is generated by `const fn` at compile time, so no floating
+//! point operations remain at runtime: the ADC reading is converted to W in 64 bit integer
+//! arithmetic, looked up by binary search and linearly interpolated.
+//!
+//! The interpolation error against the floating point conversion is below 4 m°C over the full
+//! range, the result is given in m°C.
+//!
+//! [`FixedRtd::new`] converts R0 to µΩ and is meant to be evaluated in a constant, e.g.
+//!
+//! ```
+//! # use pt_rtd::{fixed::FixedRtd, RTDType};
+//! const PT100: FixedRtd = match FixedRtd::new(RTDType::PT100) {
+//!     Ok(sensor) => sensor,
+//!     Err(_) => panic!("R0 out of range"),
+//! };
+//! ```
+
+use crate::{
+    ADCRes,
+    Curve,
+    Error,
+    RTDType,
+};
+
+/// Number of fractional bits of the tabulated resistance ratios.
+const Q: u32 = 29;
+/// Temperature of the first table entry in m°C.
+const T_START: i64 = -200_000;
+/// Temperature step of the table in m°C.
+const T_STEP: i64 = 5_000;
+/// Number of table entries.
+const TABLE_LEN: usize = 211;
+
+const TABLE_385: [u32; TABLE_LEN] = table(Curve::Alpha385);
+const TABLE_3916: [u32; TABLE_LEN] = table(Curve::Alpha3916);
+const TABLE_3920: [u32; TABLE_LEN] = table(Curve::Alpha3920);
+const TABLE_3926: [u32; TABLE_LEN] = table(Curve::Alpha3926);
+
+/// Platinum RTD prepared for integer-only conversion.
+#[derive(Debug, Clone, Copy)]
+pub struct FixedRtd {
+    /// Nominal resistance at 0°C in µΩ.
+    r_0: u64,
+    table: &'static [u32; TABLE_LEN],
+}
+
+impl FixedRtd {
+    /// Prepare a sensor type for integer-only conversion.
+    ///
+    /// R0 has to be at least 1 µΩ and small enough that R(850°C) in µΩ fits the Q29 format, i.e.
+    /// below about 8.7 kΩ.
+    pub const fn new(sensor: RTDType) -> Result<Self, Error> {
+        let table = match sensor.curve() {
+            Curve::Alpha385 => &TABLE_385,
+            Curve::Alpha3916 => &TABLE_3916,
+            Curve::Alpha3920 => &TABLE_3920,
+            Curve::Alpha3926 => &TABLE_3926,
+        };
+        let r_0 = sensor.r_0() * 1e6 + 0.5;
+        // the cast saturates, so check the range in floating point before
+        if !r_0.is_finite() || r_0 < 1_f64 || r_0 > ( u64::MAX / table[TABLE_LEN - 1] as u64 ) as f64 {
+            return Err(Error::NonexistentType);
+        }
+        Ok(FixedRtd { r_0: r_0 as u64, table })
+    }
+
+    /// Calculate temperature in m°C from resistance value in µΩ.
+    ///
+    /// Allowed temperature range: -200–850°C.
+    pub fn calc_t(&self, r: u64) -> Result<i32, Error> {
+        let table = self.table;
+        let w = match r.checked_shl(Q).filter(|w| w >> Q == r) {
+            Some(w) => w / self.r_0,
+            None => u64::MAX,
+        };
+        if w < table[0] as u64 || w > table[TABLE_LEN - 1] as u64 {
+            let (min, max) = self.range();
+            return Err(Error::OutOfRangeMicroOhm { value: r, min, max });
+        }
+
+        // find the interval table[i] <= w < table[i + 1] and interpolate linearly
+        let i = match table.binary_search(&(w as u32)) {
+            Ok(i) => return Ok(( T_START + i as i64 * T_STEP ) as i32),
+            Err(i) => i - 1,
+        };
+        let (w_0, w_1) = (table[i] as i64, table[i + 1] as i64);
+        let dt = ( ( w as i64 - w_0 ) * T_STEP + ( w_1 - w_0 ) / 2 ) / ( w_1 - w_0 );
+        Ok(( T_START + i as i64 * T_STEP + dt ) as i32)
+    }
+
+    /// Convert digital value of relative measurement for n bit ADC to temperature in m°C, see
+    /// [`conv_d_val_to_r`](crate::conv_d_val_to_r).
+    ///
+    /// Allowed temperature range: -200–850°C.
+    pub fn conv_d_val_to_t(&self, d_val: u32, r_ref: u32, res: ADCRes, pga_gain: u32) -> Result<i32, Error> {
+        let res = res as u32;
+        if pga_gain == 0 {
+            return Err(Error::ZeroGain);
+        }
+        match d_val {
+            0 => return Err(Error::AdcUnderrange { d_val: 0, min: 0 }),
+            d if d >= res => return Err(Error::AdcOverrange { d_val: d as i64, max: res as i64 }),
+            _ => {},
+        }
+        // resistance in µΩ, saturated values are out of range of the table
+        let r = ( d_val as u64 * r_ref as u64 )
+            .checked_mul(1_000_000)
+            .map_or(u64::MAX, |r| r / ( res as u64 * pga_gain as u64 ));
+        self.calc_t(r)
+    }
+
+    /// Range of resistances covered by the table in µΩ, rounded inwards.
+    fn range(&self) -> (u64, u64) {
+        let r = |w: u32| w as u128 * self.r_0 as u128;
+        let min = ( r(self.table[0]) + ( 1 << Q ) - 1 ) >> Q;
+        let max = r(self.table[TABLE_LEN - 1]) >> Q;
+        (min as u64, max as u64)
+    }
+}
+
+/// Tabulate W = R(t)/R0 in Q29 format for a curve.
+const fn table(curve: Curve) -> [u32; TABLE_LEN] {
+    let (a, b, c) = curve.coefficients();
+    let mut table = [0_u32; TABLE_LEN];
+    let mut i = 0;
+    while i < TABLE_LEN {
+        let t = ( T_START + i as i64 * T_STEP ) as f64 / 1e3;
+        let w = match t {
+            t if t >= 0_f64 => 1_f64 + a * t + b * t * t,
+            t => 1_f64 + a * t + b * t * t + c * ( t - 100_f64 ) * t * t * t,
+        };
+        table[i] = ( w * ( 1_u64 << Q ) as f64 + 0.5 ) as u32;
+        i += 1;
+    }
+    table
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        calc_r_f64,
+        calc_t_f64,
+        conv_d_val_to_r_f64,
+    };
+
+    #[test]
+    fn error_against_float() {
+        for sensor in [
+            RTDType::PT100,
+            RTDType::PT200,
+            RTDType::PT500,
+            RTDType::PT1000,
+            RTDType::PT100.with_curve(Curve::Alpha3920),
+        ] {
+            let fixed = FixedRtd::new(sensor).unwrap();
+            let mut t = -199.95;
+            while t <= 850_f64 {
+                let r = ( calc_r_f64(t, sensor).unwrap() * 1e6 ) as u64;
+                let t_fixed = fixed.calc_t(r).unwrap();
+                let t_float = calc_t_f64(r as f64 / 1e6, sensor).unwrap();
+                assert!(( t_fixed as f64 - t_float * 1e3 ).abs() < 4_f64);
+                t += 0.7;
+            }
+        }
+    }
+
+    #[test]
+    fn adc_conversion() {
+        const PT1000: FixedRtd = match FixedRtd::new(RTDType::PT1000) {
+            Ok(sensor) => sensor,
+            Err(_) => panic!(),
+        };
+        for d_val in (0..=16_777_215).step_by(9_973) {
+            let t_fixed = PT1000.conv_d_val_to_t(d_val, 4020, ADCRes::B24, 1);
+            match conv_d_val_to_r_f64(d_val, 4020, ADCRes::B24, 1).and_then(|r| calc_t_f64(r, RTDType::PT1000)) {
+                Ok(t_float) => assert!(( t_fixed.unwrap() as f64 - t_float * 1e3 ).abs() < 4_f64),
+                Err(_) => assert!(t_fixed.is_err()),
+            }
+        }
+        assert!(matches!(PT1000.conv_d_val_to_t(1_000, 4020, ADCRes::B24, 0), Err(Error::ZeroGain)));
+        assert!(matches!(PT1000.conv_d_val_to_t(0, 4020, ADCRes::B24, 1), Err(Error::AdcUnderrange { d_val: 0, min: 0 })));
+        assert!(matches!(
+            PT1000.conv_d_val_to_t(16_777_215, 4020, ADCRes::B24, 1),
+            Err(Error::AdcOverrange { d_val: 16_777_215, max: 16_777_215 }),
+        ));
+    }
+
+    #[test]
+    fn out_of_range() {
+        // R(-200°C) = 18.520 Ω and R(850°C) = 390.481 Ω
+        let pt100 = FixedRtd::new(RTDType::PT100).unwrap();
+        let (min, max) = match pt100.calc_t(10_000_000) {
+            Err(Error::OutOfRangeMicroOhm { value: 10_000_000, min, max }) => (min, max),
+            result => panic!("unexpected result {result:?}"),
+        };
+        assert!(min.abs_diff(18_520_080) <= 1 && max.abs_diff(390_481_125) <= 1);
+        assert!(pt100.calc_t(min).is_ok() && pt100.calc_t(max).is_ok());
+        assert!(matches!(pt100.calc_t(u64::MAX), Err(Error::OutOfRangeMicroOhm { .. })));
+    }
+
+    #[test]
+    fn nominal_resistance() {
+        assert!(FixedRtd::new(RTDType::PT2000).is_ok());
+        assert!(FixedRtd::new(RTDType::new(8_000_f64)).is_ok());
+        for r_0 in [f64::INFINITY, f64::NAN, -100_f64, 0_f64, 1e-7, 10_000_f64, 1e30] {
+            assert!(matches!(FixedRtd::new(RTDType::new(r_0)), Err(Error::NonexistentType)));
+        }
+    }
+}
@@ -0,0 +1,63 @@
+//! Abstraction over `f32` and `f64` for the conversion routines shared by both precisions.
+
+use core::ops::{
+    Add,
+    Div,
+    Mul,
+    Neg,
+    Sub,
+};
+
+/// Floating point type the conversions can be calculated in.
+pub(crate) trait Float:
+    Copy
+    + PartialOrd
+    + Add<Output = Self>
+    + Sub<Output = Self>
+    + Mul<Output = Self>
+    + Div<Output = Self>
+    + Neg<Output = Self>
+{
+    /// Convert a constant to the precision of the calculation.
+    fn from_f64(x: f64) -> Self;
+    /// Convert a value to `f64`, e.g. to report it in an error.
+    fn to_f64(self) -> f64;
+    fn sqrt(self) -> Self;
+    fn abs(self) -> Self;
+}
+
+impl Float for f32 {
+    fn from_f64(x: f64) -> Self {
+        x as f32
+    }
+
+    fn to_f64(self) -> f64 {
+        self as f64
+    }
+
+    fn sqrt(self) -> Self {
+        libm::sqrtf(self)
+    }
+
+    fn abs(self) -> Self {
+        libm::fabsf(self)
+    }
+}
+
+impl Float for f64 {
+    fn from_f64(x: f64) -> Self {
+        x
+    }
+
+    fn to_f64(self) -> f64 {
+        self
+    }
+
+    fn sqrt(self) -> Self {
+        libm::sqrt(self)
+    }
+
+    fn abs(self) -> Self {
+        libm::fabs(self)
+    }
+}
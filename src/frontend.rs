@@ -0,0 +1,135 @@
+//! Non-ratiometric front ends, where the ADC measures a voltage against a fixed reference.
+//!
+//! The element resistance R is calculated from the measured voltage V:
+//!
+//! - Current source: R = V / I.
+//! - Voltage divider with the element below a pull-up resistor R_pu, excited by V_exc:
+//!   V = V_exc · R / (R_pu + R).
+//! - Wheatstone bridge excited by V_exc, with the element below R_1 in one half and R_2 above R_3
+//!   in the other half. The differential output is
+//!   V = V_exc · (R / (R_1 + R) - R_3 / (R_2 + R_3)).
+//!
+//! If the ADC reference is derived from the excitation voltage, pass it as `v_ref`.
+
+use crate::{
+    adc::AdcFormat,
+    Error,
+    Quantity,
+    ResistiveSensor,
+};
+
+/// Excitation and readout circuit of a sensor. Voltages are given in V, currents in A and
+/// resistances in Ω.
+#[non_exhaustive]
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum FrontEnd {
+    /// Precision current source, the voltage across the element is measured.
+    CurrentSource { i_exc: f32 },
+    /// Voltage divider, the voltage across the element below the pull-up resistor is measured.
+    VoltageDivider { v_exc: f32, r_pullup: f32 },
+    /// Wheatstone bridge, the differential output voltage is measured.
+    WheatstoneBridge { v_exc: f32, r_1: f32, r_2: f32, r_3: f32 },
+}
+
+impl FrontEnd {
+    /// Calculate the element resistance from the measured voltage.
+    pub fn calc_r(&self, v: f32) -> Result<f32, Error> {
+        let r = match *self {
+            FrontEnd::CurrentSource { i_exc } => v / i_exc,
+            FrontEnd::VoltageDivider { v_exc, r_pullup } => {
+                check_v(v, 0_f32, v_exc)?;
+                r_pullup * v / ( v_exc - v )
+            },
+            FrontEnd::WheatstoneBridge { v_exc, r_1, r_2, r_3 } => {
+                // voltage of the element's half of the bridge relative to the excitation
+                let k = r_3 / ( r_2 + r_3 );
+                check_v(v, -k * v_exc, ( 1_f32 - k ) * v_exc)?;
+                let x = v / v_exc + k;
+                r_1 * x / ( 1_f32 - x )
+            },
+        };
+        match r {
+            r if r >= 0_f32 && r.is_finite() => Ok(r),
+            r => Err(Error::out_of_range(Quantity::Resistance, r as f64, 0_f64, f64::INFINITY)),
+        }
+    }
+
+    /// Convert code of an ADC measuring against the reference voltage `v_ref` to the element
+    /// resistance.
+    ///
+    /// Codes at the rails of the converter are rejected, see [`AdcFormat::check`].
+    pub fn conv_d_val_to_r(&self, d_val: i64, v_ref: f32, format: AdcFormat, pga_gain: u32) -> Result<f32, Error> {
+        if pga_gain == 0 {
+            return Err(Error::ZeroGain);
+        }
+        let d_val = format.check(d_val)?;
+        let v = d_val as f64 * v_ref as f64 / ( format.full_scale() as f64 * pga_gain as f64 );
+        self.calc_r(v as f32)
+    }
+
+    /// Convert code of an ADC measuring against the reference voltage `v_ref` to temperature of a
+    /// sensor in °C.
+    pub fn conv_d_val_to_t(
+        &self,
+        d_val: i64,
+        v_ref: f32,
+        format: AdcFormat,
+        pga_gain: u32,
+        sensor: &impl ResistiveSensor,
+    ) -> Result<f32, Error> {
+        sensor.temperature_at(self.conv_d_val_to_r(d_val, v_ref, format, pga_gain)?)
+    }
+}
+
+/// Check that a voltage is within `min..max`, where `max` would mean an infinite resistance.
+fn check_v(v: f32, min: f32, max: f32) -> Result<(), Error> {
+    match v {
+        v if min <= v && v < max => Ok(()),
+        v => Err(Error::out_of_range(Quantity::Voltage, v as f64, min as f64, max as f64)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::RTDType;
+    use libm::fabsf;
+
+    #[test]
+    fn resistance_from_voltage() {
+        // 138.5055 Ω, a PT100 at 100°C
+        let r = 138.5055_f32;
+        let front_ends = [
+            (FrontEnd::CurrentSource { i_exc: 1e-3 }, r * 1e-3),
+            (FrontEnd::VoltageDivider { v_exc: 3.3, r_pullup: 1000_f32 }, 3.3 * r / ( 1000_f32 + r )),
+            (
+                FrontEnd::WheatstoneBridge { v_exc: 2.5, r_1: 1000_f32, r_2: 1000_f32, r_3: 100_f32 },
+                2.5 * ( r / ( 1000_f32 + r ) - 100_f32 / 1100_f32 ),
+            ),
+        ];
+        for (front_end, v) in front_ends {
+            assert!(fabsf(front_end.calc_r(v).unwrap() - r) < 1e-3);
+        }
+
+        let divider = FrontEnd::VoltageDivider { v_exc: 3.3, r_pullup: 1000_f32 };
+        assert!(matches!(divider.calc_r(3.3), Err(Error::OutOfRange { quantity: Quantity::Voltage, .. })));
+        assert!(matches!(divider.calc_r(-0.1), Err(Error::OutOfRange { .. })));
+        let source = FrontEnd::CurrentSource { i_exc: 0_f32 };
+        assert!(matches!(source.calc_r(0.1), Err(Error::NotFinite { .. })));
+    }
+
+    #[test]
+    fn temperature_from_code() {
+        // 1 mA through a PT1000 at 0°C, 24 bit bipolar ADC with 2.5 V reference and gain 1
+        let front_end = FrontEnd::CurrentSource { i_exc: 1e-3 };
+        let format = AdcFormat::bipolar(24);
+        let d_val = ( 1_f64 / 2.5 * format.full_scale() as f64 ) as i64;
+        let t = front_end.conv_d_val_to_t(d_val, 2.5, format, 1, &RTDType::PT1000).unwrap();
+        assert!(fabsf(t) < 1e-3);
+
+        // balanced bridge with PT100 at 0°C
+        let bridge = FrontEnd::WheatstoneBridge { v_exc: 2.5, r_1: 1000_f32, r_2: 1000_f32, r_3: 100_f32 };
+        let t = bridge.conv_d_val_to_t(0, 2.5, format, 16, &RTDType::PT100).unwrap();
+        assert!(fabsf(t) < 1e-3);
+    }
+}
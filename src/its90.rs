@@ -0,0 +1,269 @@
+//! Reference and deviation functions of the International Temperature Scale of 1990 (ITS-90) for
+//! standard platinum resistance thermometers (SPRTs).
+//!
+//! Temperatures are given as T90 in °C and resistances as the ratio W = R(T90)/R(0.01°C) to the
+//! resistance at the triple point of water. Because the reference functions resolve better than
+//! 0.1 mK, all calculations in this module use `f64`.
+//!
+//! The reference functions and their inverses are those of the ITS-90 text, covering
+//! 13.8033 K–273.16 K (-259.3467–0.01°C) and 0–961.78°C. The published inverse functions agree
+//! with the reference functions to within about 0.1 mK.
+
+use libm::{
+    exp,
+    log,
+    pow,
+};
+
+use crate::{
+    Error,
+    Quantity,
+    MAX_ITERATIONS,
+};
+
+/// Triple point of water in K.
+pub const T_TPW: f64 = 273.16;
+/// Offset between °C and K.
+const T_0: f64 = 273.15;
+/// Triple point of water in °C.
+const T90_TPW: f64 = 0.01;
+
+/// Lower limit of the reference functions (triple point of equilibrium hydrogen) in °C.
+pub const T90_MIN: f64 = 13.8033 - T_0;
+/// Upper limit of the reference functions (freezing point of silver) in °C.
+pub const T90_MAX: f64 = 961.78;
+/// Reference function W_r at the lower and upper limit.
+const W_R_MIN: f64 = 0.00119007;
+const W_R_MAX: f64 = 4.28642053;
+
+const A: [f64; 13] = [-2.13534729, 3.18324720, -1.80143597, 0.71727204, 0.50344027, -0.61899395,
+    -0.05332322, 0.28021362, 0.10715224, -0.29302865, 0.04459872, 0.11868632, -0.05248134];
+const B: [f64; 16] = [0.183324722, 0.240975303, 0.209108771, 0.190439972, 0.142648498, 0.077993465,
+    0.012475611, -0.032267127, -0.075291522, -0.056470670, 0.076201285, 0.123893204, -0.029201193,
+    -0.091173542, 0.001317696, 0.026025526];
+const C: [f64; 10] = [2.78157254, 1.64650916, -0.13714390, -0.00649767, -0.00234444, 0.00511868,
+    0.00187982, -0.00204472, -0.00046122, 0.00045724];
+const D: [f64; 10] = [439.932854, 472.418020, 37.684494, 7.472018, 2.920828, 0.005184, -0.963864,
+    -0.188732, 0.191203, 0.049025];
+
+/// Calculate the reference function W_r(T90) for a temperature in °C.
+///
+/// Allowed temperature range: -259.3467–961.78°C.
+#[allow(dead_code)]
+pub fn w_r(t90: f64) -> Result<f64, Error> {
+    let t = t90 + T_0;
+    match t90 {
+        t90 if (T90_MIN..T90_TPW).contains(&t90) => {
+            Ok(exp(polynomial(&A, ( log(t / T_TPW) + 1.5 ) / 1.5)))
+        },
+        t90 if (T90_TPW..=T90_MAX).contains(&t90) => Ok(polynomial(&C, ( t - 754.15 ) / 481_f64)),
+        t90 => Err(Error::out_of_range(Quantity::Temperature, t90, T90_MIN, T90_MAX)),
+    }
+}
+
+/// Calculate the temperature T90 in °C from the reference function value W_r by the inverse
+/// reference functions.
+///
+/// Allowed temperature range: -259.3467–961.78°C.
+#[allow(dead_code)]
+pub fn t90_from_w_r(w_r: f64) -> Result<f64, Error> {
+    let t90 = match w_r {
+        w_r if w_r > 0_f64 && w_r < 1_f64 => {
+            T_TPW * polynomial(&B, ( pow(w_r, 1_f64 / 6_f64) - 0.65 ) / 0.35) - T_0
+        },
+        w_r if w_r >= 1_f64 => polynomial(&D, ( w_r - 2.64 ) / 1.64),
+        w_r => return Err(Error::out_of_range(Quantity::ResistanceRatio, w_r, W_R_MIN, W_R_MAX)),
+    };
+    // allow for the deviation between the reference functions and their inverses
+    match t90 {
+        t90 if (T90_MIN - 1e-3..=T90_MAX + 1e-3).contains(&t90) => Ok(t90),
+        _ => Err(Error::out_of_range(Quantity::ResistanceRatio, w_r, W_R_MIN, W_R_MAX)),
+    }
+}
+
+/// Deviation functions W - W_r of the ITS-90 sub-ranges.
+///
+/// The variants are named after the sub-range limits; the coefficients are those obtained by
+/// calibration at the defining fixed points of the sub-range.
+#[allow(dead_code)]
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Deviation {
+    /// 13.8033 K to 273.16 K, calibrated at the e-H2, Ne, O2, Ar, Hg and water triple points.
+    HydrogenToTpw { a: f64, b: f64, c: [f64; 5] },
+    /// 24.5561 K to 273.16 K, calibrated at the Ne, O2, Ar, Hg and water triple points.
+    NeonToTpw { a: f64, b: f64, c: [f64; 3] },
+    /// 54.3584 K to 273.16 K, calibrated at the O2, Ar, Hg and water triple points.
+    OxygenToTpw { a: f64, b: f64, c_1: f64 },
+    /// 83.8058 K to 273.16 K, calibrated at the Ar, Hg and water triple points.
+    ArgonToTpw { a: f64, b: f64 },
+    /// -38.8344°C to 29.7646°C, calibrated at the Hg triple point and Ga melting point.
+    MercuryToGallium { a: f64, b: f64 },
+    /// 0°C to 29.7646°C, calibrated at the Ga melting point.
+    TpwToGallium { a: f64 },
+    /// 0°C to 156.5985°C, calibrated at the In freezing point.
+    TpwToIndium { a: f64 },
+    /// 0°C to 231.928°C, calibrated at the In and Sn freezing points.
+    TpwToTin { a: f64, b: f64 },
+    /// 0°C to 419.527°C, calibrated at the Sn and Zn freezing points.
+    TpwToZinc { a: f64, b: f64 },
+    /// 0°C to 660.323°C, calibrated at the Sn, Zn and Al freezing points.
+    TpwToAluminium { a: f64, b: f64, c: f64 },
+    /// 0°C to 961.78°C, calibrated at the Sn, Zn, Al and Ag freezing points. `w_al` is the measured
+    /// resistance ratio at the Al freezing point, the `d` term only applies above it.
+    TpwToSilver { a: f64, b: f64, c: f64, d: f64, w_al: f64 },
+}
+
+impl Deviation {
+    /// Temperature range of the sub-range in °C.
+    pub fn range(&self) -> (f64, f64) {
+        let t_tpw = T90_TPW;
+        match self {
+            Deviation::HydrogenToTpw { .. } => (T90_MIN, t_tpw),
+            Deviation::NeonToTpw { .. } => (24.5561 - T_0, t_tpw),
+            Deviation::OxygenToTpw { .. } => (54.3584 - T_0, t_tpw),
+            Deviation::ArgonToTpw { .. } => (83.8058 - T_0, t_tpw),
+            Deviation::MercuryToGallium { .. } => (-38.8344, 29.7646),
+            Deviation::TpwToGallium { .. } => (0_f64, 29.7646),
+            Deviation::TpwToIndium { .. } => (0_f64, 156.5985),
+            Deviation::TpwToTin { .. } => (0_f64, 231.928),
+            Deviation::TpwToZinc { .. } => (0_f64, 419.527),
+            Deviation::TpwToAluminium { .. } => (0_f64, 660.323),
+            Deviation::TpwToSilver { .. } => (0_f64, T90_MAX),
+        }
+    }
+
+    /// Calculate the deviation W - W_r for the resistance ratio `w`.
+    pub fn delta_w(&self, w: f64) -> f64 {
+        let x = w - 1_f64;
+        let ln_w = log(w);
+        match *self {
+            Deviation::HydrogenToTpw { a, b, c } => {
+                a * x + b * x * x + c.iter().enumerate().map(|(i, c)| c * pow(ln_w, (i + 3) as f64)).sum::<f64>()
+            },
+            Deviation::NeonToTpw { a, b, c } => {
+                a * x + b * x * x + c.iter().enumerate().map(|(i, c)| c * pow(ln_w, (i + 1) as f64)).sum::<f64>()
+            },
+            Deviation::OxygenToTpw { a, b, c_1 } => a * x + b * x * x + c_1 * ln_w * ln_w,
+            Deviation::ArgonToTpw { a, b } => a * x + b * x * ln_w,
+            Deviation::MercuryToGallium { a, b }
+            | Deviation::TpwToTin { a, b }
+            | Deviation::TpwToZinc { a, b } => a * x + b * x * x,
+            Deviation::TpwToGallium { a } | Deviation::TpwToIndium { a } => a * x,
+            Deviation::TpwToAluminium { a, b, c } => a * x + b * x * x + c * x * x * x,
+            Deviation::TpwToSilver { a, b, c, d, w_al } => {
+                let d_term = match w {
+                    w if w > w_al => d * ( w - w_al ) * ( w - w_al ),
+                    _ => 0_f64,
+                };
+                a * x + b * x * x + c * x * x * x + d_term
+            },
+        }
+    }
+}
+
+/// Standard platinum resistance thermometer calibrated on the ITS-90.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Sprt {
+    /// Resistance at the triple point of water (0.01°C) in Ω.
+    pub r_tpw: f64,
+    /// Deviation function of the calibrated sub-range.
+    pub deviation: Deviation,
+}
+
+impl Sprt {
+    /// Create an SPRT from its resistance at the triple point of water and deviation function.
+    pub const fn new(r_tpw: f64, deviation: Deviation) -> Self {
+        Sprt { r_tpw, deviation }
+    }
+
+    /// Calculate temperature T90 in °C from resistance value.
+    ///
+    /// Allowed temperature range: the sub-range of the deviation function.
+    pub fn calc_t(&self, r: f64) -> Result<f64, Error> {
+        let w = r / self.r_tpw;
+        let t90 = t90_from_w_r(w - self.deviation.delta_w(w))?;
+        let (t_min, t_max) = self.deviation.range();
+        match t90 {
+            t90 if (t_min - 1e-3..=t_max + 1e-3).contains(&t90) => Ok(t90),
+            t90 => Err(Error::out_of_range(Quantity::Temperature, t90, t_min, t_max)),
+        }
+    }
+
+    /// Calculate resistance in Ω for a temperature T90 in °C.
+    ///
+    /// Allowed temperature range: the sub-range of the deviation function.
+    pub fn calc_r(&self, t90: f64) -> Result<f64, Error> {
+        let (t_min, t_max) = self.deviation.range();
+        if !(t_min..=t_max).contains(&t90) {
+            return Err(Error::out_of_range(Quantity::Temperature, t90, t_min, t_max));
+        }
+
+        // solve W = W_r + ΔW(W) by fixed-point iteration, ΔW is small and varies slowly with W
+        let w_r = w_r(t90)?;
+        let mut w = w_r;
+        for _ in 0..MAX_ITERATIONS {
+            let next = w_r + self.deviation.delta_w(w);
+            if ( next - w ).abs() < 1e-12 {
+                return Ok(next * self.r_tpw);
+            }
+            w = next;
+        }
+        Err(Error::NoConvergence)
+    }
+}
+
+/// Evaluate the polynomial Σ coefficients[i]·x^i.
+fn polynomial(coefficients: &[f64], x: f64) -> f64 {
+    coefficients.iter().rev().fold(0_f64, |acc, c| acc * x + c)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn reference_function_at_fixed_points() {
+        // W_r values of the defining fixed points from the ITS-90 text
+        for (t90, expected) in [
+            (83.8058 - T_0, 0.21585975),
+            (-38.8344, 0.84414211),
+            (29.7646, 1.11813889),
+            (231.928, 1.89279768),
+            (660.323, 3.37600860),
+            (961.78, 4.28642053),
+        ] {
+            assert!(( w_r(t90).unwrap() - expected ).abs() < 1e-8);
+        }
+        assert!(( w_r(0.01).unwrap() - 1_f64 ).abs() < 1e-8);
+    }
+
+    #[test]
+    fn inverse_reference_function() {
+        let mut t90 = T90_MIN;
+        while t90 <= T90_MAX {
+            assert!(( t90_from_w_r(w_r(t90).unwrap()).unwrap() - t90 ).abs() < 0.14e-3);
+            t90 += 0.5;
+        }
+        assert!(( w_r(T90_MIN).unwrap() - W_R_MIN ).abs() < 1e-8);
+        assert!(matches!(
+            t90_from_w_r(5_f64),
+            Err(Error::OutOfRange { quantity: Quantity::ResistanceRatio, max: W_R_MAX, .. }),
+        ));
+    }
+
+    #[test]
+    fn sprt_round_trip() {
+        let sprt = Sprt::new(25.5, Deviation::TpwToAluminium { a: -1.2e-4, b: 3.1e-6, c: -2.5e-7 });
+        for t90 in [0_f64, 29.7646, 156.5985, 419.527, 660.323] {
+            let r = sprt.calc_r(t90).unwrap();
+            assert!(( sprt.calc_t(r).unwrap() - t90 ).abs() < 0.14e-3);
+        }
+        assert!(matches!(sprt.calc_r(700_f64), Err(Error::OutOfRange { .. })));
+
+        let sprt = Sprt::new(25.5, Deviation::ArgonToTpw { a: -2.1e-4, b: 1.5e-5 });
+        for t90 in [sprt.deviation.range().0, -100_f64, -38.8344, 0_f64] {
+            let r = sprt.calc_r(t90).unwrap();
+            assert!(( sprt.calc_t(r).unwrap() - t90 ).abs() < 0.14e-3);
+        }
+    }
+}
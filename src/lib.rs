@@ -3,21 +3,50 @@
 //! Calculation methods for platinum type RTD temperature sensors.
 //! 
 //! All temperature related calculations are based on DIN EN 60751:2009-05.
-//! The polynomials for PT100 and PT1000 for temperature calculation at below 0°C are from
-//! https://github.com/ulikoehler/UliEngineering/blob/master/UliEngineering/Physics/RTD.py. 
+//! The polynomial for temperature calculation at below 0°C follows the approach of
+//! https://github.com/ulikoehler/UliEngineering/blob/master/UliEngineering/Physics/RTD.py,
+//! the coefficients for each curve are derived from the Callendar–Van Dusen equation at compile
+//! time.
 //! 
 //! See also https://techoverflow.net/2016/01/02/accurate-calculation-of-pt100pt1000-temperature-from-resistance/
 //! for reference.
 //! 
-//! The correctional polynomials for PT200 and PT500 are not yet implemented, temperature 
-//! calculations for these below 0°C will be wrong.
+//! The correctional polynomial is applied to the resistance scaled to R0 = 100 Ω, so it is valid
+//! for any nominal R0.
+
+use core::fmt;
 
 use libm::{
     powf,
     sqrtf,
-    floorf,
 };
 
+use float::Float;
+
+#[cfg(all(test, feature = "async", any(feature = "max31865", feature = "ads1220", feature = "ads124s08")))]
+mod executor;
+mod float;
+#[cfg(test)]
+mod iec60751;
+
+pub mod adc;
+#[cfg(any(feature = "ads1220", feature = "ads124s08"))]
+pub mod ads;
+pub mod copper;
+pub mod fault;
+pub mod fit;
+pub mod fixed;
+pub mod frontend;
+pub mod its90;
+pub mod lut;
+#[cfg(feature = "max31865")]
+pub mod max31865;
+pub mod nickel;
+pub mod self_heating;
+pub mod tolerance;
+pub mod uncertainty;
+pub mod wiring;
+
 #[allow(dead_code)]
 #[non_exhaustive]
 #[derive(Clone, Copy)]
@@ -33,14 +62,115 @@ pub enum ADCRes {
     B24 = 16_777_215,
 }
 
-#[allow(dead_code)]
+/// Common interface of all resistance–temperature conversion models.
+/// 
+/// Temperatures are given in °C, resistances in Ω.
+pub trait ResistiveSensor {
+    /// Calculate resistance of the sensor for a specified temperature.
+    fn resistance_at(&self, t: f32) -> Result<f32, Error>;
+
+    /// Calculate temperature of the sensor from resistance value.
+    fn temperature_at(&self, r: f32) -> Result<f32, Error>;
+
+    /// Allowed temperature range as `(min, max)`.
+    fn valid_range(&self) -> (f32, f32);
+
+    /// Calculate sensitivity dR/dt of the sensor in Ω/K for a specified temperature.
+    /// 
+    /// The default implementation uses a central difference of ±0.1 K.
+    fn sensitivity_at(&self, t: f32) -> Result<f32, Error> {
+        let (t_min, t_max) = self.valid_range();
+        let t_lo = ( t - 0.1 ).max(t_min);
+        let t_hi = ( t + 0.1 ).min(t_max);
+        Ok(( self.resistance_at(t_hi)? - self.resistance_at(t_lo)? ) / ( t_hi - t_lo ))
+    }
+}
+
+/// Platinum RTD of arbitrary nominal resistance R0 at 0°C.
+/// 
+/// The common sensor types are available as constants, e.g. `RTDType::PT100`, other sensors (e.g.
+/// probes trimmed to a non-integer R0) can be created with [`RTDType::new`]. All sensor types
+/// follow the IEC 60751 curve unless a different curve is selected with [`RTDType::with_curve`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct RTDType {
+    r_0: f64,
+    curve: Curve,
+}
+
+impl RTDType {
+    pub const PT10: RTDType = RTDType::new(10_f64);
+    pub const PT50: RTDType = RTDType::new(50_f64);
+    pub const PT100: RTDType = RTDType::new(100_f64);
+    pub const PT200: RTDType = RTDType::new(200_f64);
+    pub const PT500: RTDType = RTDType::new(500_f64);
+    pub const PT1000: RTDType = RTDType::new(1000_f64);
+    pub const PT2000: RTDType = RTDType::new(2000_f64);
+
+    /// Create a sensor type with nominal resistance `r_0` at 0°C in Ω.
+    pub const fn new(r_0: f64) -> Self {
+        RTDType { r_0, curve: Curve::Alpha385 }
+    }
+
+    /// Select the curve standard of the sensor.
+    pub const fn with_curve(self, curve: Curve) -> Self {
+        RTDType { curve, ..self }
+    }
+
+    /// Nominal resistance at 0°C in Ω.
+    pub const fn r_0(&self) -> f64 {
+        self.r_0
+    }
+
+    /// Curve standard of the sensor.
+    pub const fn curve(&self) -> Curve {
+        self.curve
+    }
+
+    /// Check for a physically meaningful R0.
+    fn checked(self) -> Result<Self, Error> {
+        match self.r_0 {
+            r_0 if r_0.is_finite() && r_0 > 0_f64 => Ok(self),
+            _ => Err(Error::NonexistentType),
+        }
+    }
+
+    /// Standard coefficients of the curve in the precision of the calculation.
+    fn cvd<F: Float>(self) -> Result<Cvd<F>, Error> {
+        let (a, b, c) = self.checked()?.curve.coefficients();
+        Ok(Cvd {
+            r_0: F::from_f64(self.r_0),
+            a: F::from_f64(a),
+            b: F::from_f64(b),
+            c: F::from_f64(c),
+        })
+    }
+}
+
+/// Curve standards for platinum RTDs, named after their mean temperature coefficient α between
+/// 0°C and 100°C.
 #[non_exhaustive]
-#[derive(Clone, Copy)]
-pub enum RTDType {
-    PT100 = 100,
-    PT200 = 200,
-    PT500 = 500,
-    PT1000 = 1000,
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Curve {
+    /// α = 0.00385, IEC 60751 / DIN EN 60751.
+    Alpha385,
+    /// α = 0.003916, JIS C 1604-1981.
+    Alpha3916,
+    /// α = 0.003920, US industrial standard (SAMA RC-4-1966).
+    Alpha3920,
+    /// α = 0.003926, US laboratory standard.
+    Alpha3926,
+}
+
+impl Curve {
+    /// Callendar–Van Dusen coefficients A, B and C of the curve.
+    pub const fn coefficients(&self) -> (f64, f64, f64) {
+        match self {
+            Curve::Alpha385 => (A, B, C),
+            Curve::Alpha3916 => (3.9739e-3, -5.8700e-7, -4.4000e-12),
+            Curve::Alpha3920 => (3.9787e-3, -5.8686e-7, -4.1670e-12),
+            Curve::Alpha3926 => (3.9848e-3, -5.8700e-7, -4.0000e-12),
+        }
+    }
 }
 
 #[allow(dead_code)]
@@ -48,51 +178,176 @@ pub enum RTDType {
 struct RTDCorrection;
 
 impl RTDCorrection {
-    pub const PT100: Polynomial = [1.51892983e-10, -2.85842067e-08, -5.34227299e-06,
-    1.80282972e-03, -1.61875985e-01, 4.84112370e+00];
-    pub const PT200: Polynomial = [0_f32; 6]; // FIXME: Precalculate correctional polynomial for PT200
-    pub const PT500: Polynomial = [0_f32; 6]; // FIXME: Precalculate correctional polynomial for PT500
-    pub const PT1000: Polynomial = [1.51892983e-15, -2.85842067e-12, -5.34227299e-09,
-    1.80282972e-05, -1.61875985e-02, 4.84112370e+00];
+    /// Correctional polynomials for R0 = 100 Ω, coefficients in order of descending power.
+    pub const ALPHA_385: Polynomial = RTDCorrection::derive(Curve::Alpha385);
+    pub const ALPHA_3916: Polynomial = RTDCorrection::derive(Curve::Alpha3916);
+    pub const ALPHA_3920: Polynomial = RTDCorrection::derive(Curve::Alpha3920);
+    pub const ALPHA_3926: Polynomial = RTDCorrection::derive(Curve::Alpha3926);
+
+    /// Correctional polynomial of a curve for R0 = 100 Ω.
+    const fn of(curve: Curve) -> Polynomial {
+        match curve {
+            Curve::Alpha385 => RTDCorrection::ALPHA_385,
+            Curve::Alpha3916 => RTDCorrection::ALPHA_3916,
+            Curve::Alpha3920 => RTDCorrection::ALPHA_3920,
+            Curve::Alpha3926 => RTDCorrection::ALPHA_3926,
+        }
+    }
+
+    /// Derive the correctional polynomial of a curve for R0 = 100 Ω at compile time.
+    /// 
+    /// The difference between the exact temperature and the quadratic solution is fitted by least
+    /// squares every 0.5°C between -200°C and 0°C. The fit is done in the resistance mapped to
+    /// [-1, 1] to keep the normal equations well conditioned and then expanded in powers of R.
+    const fn derive(curve: Curve) -> Polynomial {
+        const N: usize = 6;
+        let (a, b, c) = curve.coefficients();
+        let r_0 = 100_f64;
+        let r_min = cvd_r(-200_f64, r_0, a, b, c);
+        let (mid, half) = (( r_0 + r_min ) / 2_f64, ( r_0 - r_min ) / 2_f64);
+
+        // normal equations of the fit in z = (r - mid) / half
+        let mut m = [[0_f64; N + 1]; N];
+        let mut i = 0;
+        while i <= 400 {
+            let t = -( i as f64 ) / 2_f64;
+            let r = cvd_r(t, r_0, a, b, c);
+            let t_quad = ( -r_0 * a + const_sqrt(r_0 * r_0 * a * a - 4_f64 * r_0 * b * ( r_0 - r )) ) / ( 2_f64 * r_0 * b );
+            let z = ( r - mid ) / half;
+
+            let mut powers = [1_f64; N];
+            let mut k = 1;
+            while k < N {
+                powers[k] = powers[k - 1] * z;
+                k += 1;
+            }
+            let mut row = 0;
+            while row < N {
+                let mut col = 0;
+                while col < N {
+                    m[row][col] += powers[row] * powers[col];
+                    col += 1;
+                }
+                m[row][N] += powers[row] * ( t - t_quad );
+                row += 1;
+            }
+            i += 1;
+        }
+
+        // Gaussian elimination with partial pivoting
+        let mut col = 0;
+        while col < N {
+            let mut pivot = col;
+            let mut row = col + 1;
+            while row < N {
+                if m[row][col].abs() > m[pivot][col].abs() {
+                    pivot = row;
+                }
+                row += 1;
+            }
+            let tmp = m[col];
+            m[col] = m[pivot];
+            m[pivot] = tmp;
+
+            let mut row = col + 1;
+            while row < N {
+                let factor = m[row][col] / m[col][col];
+                let mut k = col;
+                while k <= N {
+                    m[row][k] -= factor * m[col][k];
+                    k += 1;
+                }
+                row += 1;
+            }
+            col += 1;
+        }
+        let mut d = [0_f64; N];
+        let mut row = N;
+        while row > 0 {
+            row -= 1;
+            let mut sum = m[row][N];
+            let mut k = row + 1;
+            while k < N {
+                sum -= m[row][k] * d[k];
+                k += 1;
+            }
+            d[row] = sum / m[row][row];
+        }
+
+        // expand Σ d_k z^k with z = r / half - mid / half by Horner's scheme, in ascending powers of r
+        let (s, o) = (1_f64 / half, -mid / half);
+        let mut p = [0_f64; N];
+        let mut k = N;
+        while k > 0 {
+            k -= 1;
+            let mut j = N - 1;
+            while j > 0 {
+                p[j] = p[j] * o + p[j - 1] * s;
+                j -= 1;
+            }
+            p[0] = p[0] * o + d[k];
+        }
+
+        let mut poly = [0_f32; N];
+        let mut j = 0;
+        while j < N {
+            poly[j] = p[N - 1 - j] as f32;
+            j += 1;
+        }
+        poly
+    }
 }
 type Polynomial = [f32; 6];
 
-const A: f32 = 3.9083e-3;
-const B: f32 = -5.7750e-7;
-const C: f32 = -4.1830e-12;
+const A: f64 = 3.9083e-3;
+const B: f64 = -5.7750e-7;
+const C: f64 = -4.1830e-12;
+
+/// Maximum number of Newton–Raphson iterations used by [`solve_t`] and [`CvdCoefficients::solve_t`].
+pub const MAX_ITERATIONS: u32 = 32;
+
+/// Tolerance of the resistance range relative to R0, half a digit of the IEC 60751 table (0.005 Ω
+/// for a PT100).
+/// 
+/// The table values at -200°C and 850°C are rounded beyond the resistances of the curve, so
+/// resistances within this tolerance of the range limits are accepted by the temperature
+/// calculations and result in -200°C or 850°C.
+pub const R_TOLERANCE: f64 = 5e-5;
+
+/// Result of the iterative inversion of the Callendar–Van Dusen equation in single (`f32`) or
+/// double (`f64`) precision.
+#[derive(Debug, Clone, Copy)]
+pub struct Solution<F = f32> {
+    /// Temperature in °C.
+    pub t: F,
+    /// Number of Newton–Raphson iterations performed.
+    pub iterations: u32,
+    /// Difference between `calc_r(t)` and the requested resistance in Ω.
+    pub residual: F,
+}
 
 /// Calculate temperature of RTD from resistance value.
 /// 
-/// Allowed temperature range: -200–850°C.
+/// Allowed temperature range: -200–850°C. Resistances up to [`R_TOLERANCE`] beyond the range
+/// are accepted and result in the limits of the range.
 #[allow(dead_code)]
 pub fn calc_t(r: f32, r_0: RTDType) -> Result<f32, Error> {
-    let r_min = floorf(calc_r(-200_f32, r_0)?) as i32;
-    let r_max = floorf(calc_r(850_f32, r_0)?) as i32;
-
-    // set correctional polynomial for t < 0°C
-    let corr_poly: Result<[f32; 6], Error> = match r_0 {
-        RTDType::PT100 => Ok(RTDCorrection::PT100),
-        RTDType::PT200 => Ok(RTDCorrection::PT200),
-        RTDType::PT500 => Ok(RTDCorrection::PT500),
-        RTDType::PT1000 => Ok(RTDCorrection::PT1000),
-    };
+    r_0.cvd::<f32>()?.check_r(r)?;
+
+    let sensor = r_0;
+    let (a, b, _) = r_0.curve().coefficients();
+    let (a, b) = (a as f32, b as f32);
 
     // cast r_0 to f32 for calculation
-    let r_0 = r_0 as i32 as f32;
-    let t = ( -r_0 * A + sqrtf( powf(r_0, 2_f32) * powf(A, 2_f32) - 4_f32 * r_0 * B * ( r_0 - r as f32 ) ) ) / ( 2_f32 * r_0 as f32 * B );
-
-    match corr_poly {
-        Ok(poly) => {
-            match (floorf(r) as i32, r_0 as i32) {
-                (r, r_0) if r_0 <= r && r <= r_max => Ok(t), // t >= 0°C
-                (r, r_0) if r_min <= r && r < r_0 => Ok(
-                    t + poly_correction(r as f32, poly) // t < 0°C, apply the correctional polynomial
-                ), 
-                _ => Err(Error::OutOfBounds),
-            }
-        },
-        Err(_) => Err(Error::NonexistentType),
-    }
+    let r_0 = r_0.r_0() as f32;
+    let t = ( -r_0 * a + sqrtf( powf(r_0, 2_f32) * powf(a, 2_f32) - 4_f32 * r_0 * b * ( r_0 - r ) ) ) / ( 2_f32 * r_0 * b );
+
+    let t = match r {
+        r if r >= r_0 => t, // t >= 0°C
+        // t < 0°C, apply the correctional polynomial to the resistance scaled to PT100
+        r => t + poly_correction(r * 100_f32 / r_0, RTDCorrection::of(sensor.curve())),
+    };
+    Ok(t.clamp(-200_f32, 850_f32))
 }
 
 /// Calculate resistance of RTD for a specified temperature.
@@ -101,21 +356,284 @@ pub fn calc_t(r: f32, r_0: RTDType) -> Result<f32, Error> {
 /// over the full range) is introduced due to the use of polynomial approximation.
 #[allow(dead_code)]
 pub fn calc_r(t: f32, r_0: RTDType) -> Result<f32, Error> {
-    let r_0 = r_0 as i32;
-    match floorf(t) as i32 {
-        0..=850 => Ok(r_0 as f32 * ( 1_f32 + A * t + B * powf(t, 2_f32) )),
-        -200..=-1 => Ok(r_0 as f32 * ( 1_f32 + A * t + B * powf(t, 2_f32) + C * ( t - 100_f32 ) * powf(t, 3_f32) )),
-        _ => Err(Error::OutOfBounds),
+    CvdCoefficients::from(r_0.checked()?).calc_r(t)
+}
+
+/// Calculate temperature of RTD from resistance value by inverting the full Callendar–Van Dusen
+/// equation.
+/// 
+/// Unlike [`calc_t`], no correctional polynomial is used for temperatures below 0°C. Instead the
+/// quartic equation including the `C` term is solved by Newton–Raphson iteration, starting from
+/// the quadratic solution, until the residual resistance is within `tolerance` (in Ω).
+/// 
+/// `f32` resolves about 1e-7 of the resistance, e.g. 30 µΩ at 390 Ω, so smaller tolerances may not
+/// converge. Use [`solve_t_f64`] for residuals in the µΩ range.
+/// 
+/// Allowed temperature range: -200–850°C.
+#[allow(dead_code)]
+pub fn solve_t(r: f32, r_0: RTDType, tolerance: f32) -> Result<Solution, Error> {
+    CvdCoefficients::from(r_0.checked()?).solve_t(r, tolerance)
+}
+
+/// Calculate resistance of RTD for a specified temperature in double precision.
+/// 
+/// Allowed temperature range: -200–850°C.
+#[allow(dead_code)]
+pub fn calc_r_f64(t: f64, r_0: RTDType) -> Result<f64, Error> {
+    r_0.cvd()?.calc_r(t)
+}
+
+/// Calculate temperature of RTD from resistance value in double precision.
+/// 
+/// The full Callendar–Van Dusen equation is solved to within 1e-12 of R0 (see [`solve_t_f64`]),
+/// which keeps the round trip with [`calc_r_f64`] well below 1 µK.
+/// 
+/// Allowed temperature range: -200–850°C.
+#[allow(dead_code)]
+pub fn calc_t_f64(r: f64, r_0: RTDType) -> Result<f64, Error> {
+    solve_t_f64(r, r_0, r_0.r_0() * 1e-12).map(|solution| solution.t)
+}
+
+/// Calculate temperature of RTD from resistance value in double precision by inverting the full
+/// Callendar–Van Dusen equation, see [`solve_t`].
+/// 
+/// Allowed temperature range: -200–850°C.
+#[allow(dead_code)]
+pub fn solve_t_f64(r: f64, r_0: RTDType, tolerance: f64) -> Result<Solution<f64>, Error> {
+    r_0.cvd()?.solve_t(r, tolerance)
+}
+
+/// Callendar–Van Dusen coefficients of a platinum RTD.
+/// 
+/// The standard curves are available via `CvdCoefficients::from(RTDType)`, individual
+/// coefficients (e.g. from a calibration certificate) can be set with [`CvdCoefficients::new`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct CvdCoefficients {
+    /// Resistance at 0°C in Ω.
+    pub r_0: f64,
+    pub a: f64,
+    pub b: f64,
+    pub c: f64,
+}
+
+impl CvdCoefficients {
+    /// Create a set of coefficients from R0 (in Ω) and A, B and C.
+    pub const fn new(r_0: f64, a: f64, b: f64, c: f64) -> Self {
+        CvdCoefficients { r_0, a, b, c }
+    }
+
+    /// Calculate resistance of the sensor for a specified temperature.
+    /// 
+    /// Allowed temperature range: -200–850°C.
+    pub fn calc_r(&self, t: f32) -> Result<f32, Error> {
+        self.cvd().calc_r(t)
+    }
+
+    /// Calculate temperature of the sensor from resistance value.
+    /// 
+    /// The result is solved to within 1 ppm of R0, see [`CvdCoefficients::solve_t`].
+    /// 
+    /// Allowed temperature range: -200–850°C.
+    pub fn calc_t(&self, r: f32) -> Result<f32, Error> {
+        self.solve_t(r, self.r_0 as f32 * 1e-6).map(|solution| solution.t)
+    }
+
+    /// Calculate temperature of the sensor from resistance value by Newton–Raphson iteration until
+    /// the residual resistance is within `tolerance` (in Ω).
+    /// 
+    /// Allowed temperature range: -200–850°C.
+    pub fn solve_t(&self, r: f32, tolerance: f32) -> Result<Solution, Error> {
+        self.cvd().solve_t(r, tolerance)
+    }
+
+    /// Calculate resistance of the sensor for a specified temperature in double precision.
+    /// 
+    /// Allowed temperature range: -200–850°C.
+    pub fn calc_r_f64(&self, t: f64) -> Result<f64, Error> {
+        self.cvd().calc_r(t)
+    }
+
+    /// Calculate temperature of the sensor from resistance value in double precision.
+    /// 
+    /// The result is solved to within 1e-12 of R0. Allowed temperature range: -200–850°C.
+    pub fn calc_t_f64(&self, r: f64) -> Result<f64, Error> {
+        self.cvd().solve_t(r, self.r_0 * 1e-12).map(|solution| solution.t)
+    }
+
+    /// Coefficients in the precision of the calculation.
+    fn cvd<F: Float>(&self) -> Cvd<F> {
+        Cvd {
+            r_0: F::from_f64(self.r_0),
+            a: F::from_f64(self.a),
+            b: F::from_f64(self.b),
+            c: F::from_f64(self.c),
+        }
+    }
+}
+
+impl ResistiveSensor for CvdCoefficients {
+    fn resistance_at(&self, t: f32) -> Result<f32, Error> {
+        self.calc_r(t)
+    }
+
+    fn temperature_at(&self, r: f32) -> Result<f32, Error> {
+        self.calc_t(r)
+    }
+
+    fn valid_range(&self) -> (f32, f32) {
+        (-200_f32, 850_f32)
+    }
+
+    fn sensitivity_at(&self, t: f32) -> Result<f32, Error> {
+        self.calc_r(t)?;
+        Ok(self.cvd().dr_dt(t))
     }
 }
 
+impl ResistiveSensor for RTDType {
+    fn resistance_at(&self, t: f32) -> Result<f32, Error> {
+        calc_r(t, *self)
+    }
+
+    fn temperature_at(&self, r: f32) -> Result<f32, Error> {
+        calc_t(r, *self)
+    }
+
+    fn valid_range(&self) -> (f32, f32) {
+        (-200_f32, 850_f32)
+    }
+
+    fn sensitivity_at(&self, t: f32) -> Result<f32, Error> {
+        CvdCoefficients::from(self.checked()?).sensitivity_at(t)
+    }
+}
+
+impl From<RTDType> for CvdCoefficients {
+    /// Standard coefficients of the curve of the sensor type.
+    fn from(r_0: RTDType) -> Self {
+        let (a, b, c) = r_0.curve().coefficients();
+        CvdCoefficients::new(r_0.r_0(), a, b, c)
+    }
+}
+
+/// Callendar–Van Dusen equation in the precision of the calculation.
+#[derive(Clone, Copy)]
+struct Cvd<F> {
+    r_0: F,
+    a: F,
+    b: F,
+    c: F,
+}
+
+impl<F: Float> Cvd<F> {
+    fn calc_r(&self, t: F) -> Result<F, Error> {
+        match t {
+            t if t >= F::from_f64(-200_f64) && t <= F::from_f64(850_f64) => Ok(self.r(t)),
+            t => Err(Error::out_of_range(Quantity::Temperature, t.to_f64(), -200_f64, 850_f64)),
+        }
+    }
+
+    /// Check that a resistance is within the range of -200–850°C, extended by [`R_TOLERANCE`].
+    fn check_r(&self, r: F) -> Result<(), Error> {
+        let (r_min, r_max) = (self.r(F::from_f64(-200_f64)), self.r(F::from_f64(850_f64)));
+        let tolerance = self.r_0 * F::from_f64(R_TOLERANCE);
+        match r {
+            r if r >= r_min - tolerance && r <= r_max + tolerance => Ok(()),
+            r => Err(Error::out_of_range(Quantity::Resistance, r.to_f64(), r_min.to_f64(), r_max.to_f64())),
+        }
+    }
+
+    fn solve_t(&self, r: F, tolerance: F) -> Result<Solution<F>, Error> {
+        self.check_r(r)?;
+
+        // start from the solution of the quadratic equation, which is exact for t >= 0°C
+        let (r_0, a, b) = (self.r_0, self.a, self.b);
+        let one = F::from_f64(1_f64);
+        let t = match b {
+            b if b == F::from_f64(0_f64) => ( r / r_0 - one ) / a,
+            b => ( -r_0 * a + ( r_0 * r_0 * a * a - F::from_f64(4_f64) * r_0 * b * ( r_0 - r ) ).sqrt() ) / ( F::from_f64(2_f64) * r_0 * b ),
+        };
+        let mut solution = newton_raphson(r, t, tolerance, |t| self.r(t), |t| self.dr_dt(t))?;
+
+        // keep results at the range limits within the range despite rounding
+        let (t_min, t_max) = (F::from_f64(-200_f64), F::from_f64(850_f64));
+        if solution.t < t_min {
+            solution.t = t_min;
+        } else if solution.t > t_max {
+            solution.t = t_max;
+        }
+        Ok(solution)
+    }
+
+    /// Evaluate the Callendar–Van Dusen equation without range checks.
+    fn r(&self, t: F) -> F {
+        let (r_0, a, b, c) = (self.r_0, self.a, self.b, self.c);
+        let one = F::from_f64(1_f64);
+        match t {
+            t if t >= F::from_f64(0_f64) => r_0 * ( one + a * t + b * t * t ),
+            t => r_0 * ( one + a * t + b * t * t + c * ( t - F::from_f64(100_f64) ) * t * t * t ),
+        }
+    }
+
+    /// Evaluate the derivative dR/dt of the Callendar–Van Dusen equation.
+    fn dr_dt(&self, t: F) -> F {
+        let (r_0, a, b, c) = (self.r_0, self.a, self.b, self.c);
+        let two = F::from_f64(2_f64);
+        match t {
+            t if t >= F::from_f64(0_f64) => r_0 * ( a + two * b * t ),
+            t => r_0 * ( a + two * b * t + c * ( F::from_f64(4_f64) * t - F::from_f64(300_f64) ) * t * t ),
+        }
+    }
+}
+
+/// Solve `r_at(t) = r` for `t` by Newton–Raphson iteration, starting from `t`.
+pub(crate) fn newton_raphson<F: Float>(
+    r: F,
+    mut t: F,
+    tolerance: F,
+    r_at: impl Fn(F) -> F,
+    dr_dt: impl Fn(F) -> F,
+) -> Result<Solution<F>, Error> {
+    let mut residual = r_at(t) - r;
+    for iterations in 0..=MAX_ITERATIONS {
+        if residual.abs() <= tolerance {
+            return Ok(Solution { t, iterations, residual });
+        }
+        let step = residual / dr_dt(t);
+        if step == F::from_f64(0_f64) {
+            break; // no further progress possible at this precision
+        }
+        t = t - step;
+        residual = r_at(t) - r;
+    }
+    Err(Error::NoConvergence)
+}
+
 /// Convert digital value of relative measurement for n bit ADC to resistance.
+///
+/// The codes 0 and 2^n - 1 are the rails of the converter and return [`Error::AdcUnderrange`] and
+/// [`Error::AdcOverrange`], as for [`adc::AdcFormat::check`].
 #[allow(dead_code)]
 pub fn conv_d_val_to_r(d_val: u32, r_ref: u32, res: ADCRes, pga_gain: u32) -> Result<f32, Error> {
     let res = res as u32;
     match d_val {
-        d if d <= res => Ok(d_val as f32 * r_ref as f32 / ( res as f32 * pga_gain as f32)),
-        _ => Err(Error::OutOfBounds),
+        _ if pga_gain == 0 => Err(Error::ZeroGain),
+        0 => Err(Error::AdcUnderrange { d_val: 0, min: 0 }),
+        d if d < res => Ok(d_val as f32 * r_ref as f32 / ( res as f32 * pga_gain as f32)),
+        d => Err(Error::AdcOverrange { d_val: d as i64, max: res as i64 }),
+    }
+}
+
+/// Convert digital value of relative measurement for n bit ADC to resistance in double precision,
+/// see [`conv_d_val_to_r`].
+#[allow(dead_code)]
+pub fn conv_d_val_to_r_f64(d_val: u32, r_ref: u32, res: ADCRes, pga_gain: u32) -> Result<f64, Error> {
+    let res = res as u32;
+    match d_val {
+        _ if pga_gain == 0 => Err(Error::ZeroGain),
+        0 => Err(Error::AdcUnderrange { d_val: 0, min: 0 }),
+        d if d < res => Ok(d_val as f64 * r_ref as f64 / ( res as f64 * pga_gain as f64)),
+        d => Err(Error::AdcOverrange { d_val: d as i64, max: res as i64 }),
     }
 }
 
@@ -123,21 +641,147 @@ pub fn conv_d_val_to_r(d_val: u32, r_ref: u32, res: ADCRes, pga_gain: u32) -> Re
 #[allow(dead_code)]
 fn poly_correction(r: f32, poly: Polynomial) -> f32 {
     let mut res = 0_f32;
-    for (i, factor) in poly.iter().enumerate() {
-        res += factor * powf(r, i as f32);
+    for factor in poly.iter() {
+        res = res * r + factor;
     };    
     res
 }
 
-#[derive(Debug)]
+/// Evaluate the Callendar–Van Dusen equation at compile time.
+pub(crate) const fn cvd_r(t: f64, r_0: f64, a: f64, b: f64, c: f64) -> f64 {
+    match t {
+        t if t >= 0_f64 => r_0 * ( 1_f64 + a * t + b * t * t ),
+        t => r_0 * ( 1_f64 + a * t + b * t * t + c * ( t - 100_f64 ) * t * t * t ),
+    }
+}
+
+/// Evaluate the derivative dR/dt of the Callendar–Van Dusen equation at compile time.
+pub(crate) const fn cvd_dr_dt(t: f64, r_0: f64, a: f64, b: f64, c: f64) -> f64 {
+    match t {
+        t if t >= 0_f64 => r_0 * ( a + 2_f64 * b * t ),
+        t => r_0 * ( a + 2_f64 * b * t + c * ( 4_f64 * t - 300_f64 ) * t * t ),
+    }
+}
+
+/// Square root of a positive number at compile time by Heron's method.
+const fn const_sqrt(x: f64) -> f64 {
+    let mut y = if x > 1_f64 { x } else { 1_f64 };
+    let mut n = 0;
+    while n < 64 {
+        y = ( y + x / y ) / 2_f64;
+        n += 1;
+    }
+    y
+}
+
+/// Errors of the conversions.
+#[non_exhaustive]
+#[derive(Debug, Clone, Copy, PartialEq)]
 pub enum Error {
-    OutOfBounds,
+    /// An input is outside of the range the conversion is defined for.
+    OutOfRange {
+        quantity: Quantity,
+        value: f64,
+        min: f64,
+        max: f64,
+    },
+    /// A resistance in µΩ is outside of the range of the integer-only conversion, see
+    /// [`fixed`].
+    OutOfRangeMicroOhm { value: u64, min: u64, max: u64 },
+    /// The digital value is above the full scale of the ADC.
+    AdcOverrange { d_val: i64, max: i64 },
+    /// The digital value is below the (negative) full scale of the ADC.
+    AdcUnderrange { d_val: i64, min: i64 },
+    /// The PGA gain is zero.
+    ZeroGain,
+    /// An input is NaN or infinite.
+    NotFinite { quantity: Quantity },
+    /// The nominal resistance of the sensor type is not positive and finite.
     NonexistentType,
+    /// The iterative solution did not converge within [`MAX_ITERATIONS`].
+    NoConvergence,
+    /// The data points do not determine all coefficients of a fit.
+    InsufficientData,
+}
+
+impl Error {
+    /// Error for a value outside of `min..=max`, or for a value that is not finite at all.
+    pub(crate) fn out_of_range(quantity: Quantity, value: f64, min: f64, max: f64) -> Self {
+        match value {
+            value if value.is_finite() => Error::OutOfRange { quantity, value, min, max },
+            _ => Error::NotFinite { quantity },
+        }
+    }
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::OutOfRange { quantity, value, min, max } => {
+                let unit = quantity.unit();
+                write!(f, "{quantity} of {value}{unit} out of range {min}{unit} to {max}{unit}")
+            },
+            Error::OutOfRangeMicroOhm { value, min, max } => {
+                write!(f, "resistance of {value} µΩ out of range {min} µΩ to {max} µΩ")
+            },
+            Error::AdcOverrange { d_val, max } => write!(f, "ADC overrange: {d_val} above {max}"),
+            Error::AdcUnderrange { d_val, min } => write!(f, "ADC underrange: {d_val} below {min}"),
+            Error::ZeroGain => write!(f, "PGA gain is zero"),
+            Error::NotFinite { quantity } => write!(f, "{quantity} is not finite"),
+            Error::NonexistentType => write!(f, "nominal resistance is not positive and finite"),
+            Error::NoConvergence => write!(f, "no convergence within {MAX_ITERATIONS} iterations"),
+            Error::InsufficientData => write!(f, "insufficient data to determine all coefficients"),
+        }
+    }
+}
+
+impl core::error::Error for Error {}
+
+/// Physical quantity of an input, used to describe errors.
+#[non_exhaustive]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Quantity {
+    /// Temperature in °C.
+    Temperature,
+    /// Resistance in Ω.
+    Resistance,
+    /// Resistance ratio W = R(t)/R(t_ref), dimensionless.
+    ResistanceRatio,
+    /// Voltage in V.
+    Voltage,
+    /// Dissipation constant of a sensor in mW/K.
+    DissipationConstant,
+}
+
+impl Quantity {
+    /// Unit symbol of the quantity.
+    pub const fn unit(&self) -> &'static str {
+        match self {
+            Quantity::Temperature => " °C",
+            Quantity::Resistance => " Ω",
+            Quantity::ResistanceRatio => "",
+            Quantity::Voltage => " V",
+            Quantity::DissipationConstant => " mW/K",
+        }
+    }
+}
+
+impl fmt::Display for Quantity {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Quantity::Temperature => write!(f, "temperature"),
+            Quantity::Resistance => write!(f, "resistance"),
+            Quantity::ResistanceRatio => write!(f, "resistance ratio"),
+            Quantity::Voltage => write!(f, "voltage"),
+            Quantity::DissipationConstant => write!(f, "dissipation constant"),
+        }
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use libm::fabsf;
 
     #[test]
     fn resistance_calculation() {
@@ -154,4 +798,215 @@ mod tests {
         let t = calc_t(r, RTDType::PT100).unwrap();
         assert_eq!(t, 0_f32);
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn sub_zero_reference_table() {
+        // IEC 60751 reference values in Ω for PT100, PT200 and PT500
+        let table: [(f32, [f32; 3]); 7] = [
+            (-190_f32, [22.83, 45.65, 114.13]),
+            (-150_f32, [39.72, 79.45, 198.62]),
+            (-100_f32, [60.26, 120.51, 301.28]),
+            (-50_f32, [80.31, 160.61, 401.53]),
+            (-30_f32, [88.22, 176.44, 441.11]),
+            (-20_f32, [92.16, 184.32, 460.80]),
+            (-10_f32, [96.09, 192.17, 480.43]),
+        ];
+        for (t, values) in table {
+            for (r_0, r) in [RTDType::PT100, RTDType::PT200, RTDType::PT500].into_iter().zip(values) {
+                // the table is rounded to 0.01 Ω
+                assert!(fabsf(calc_r(t, r_0).unwrap() - r) <= 0.005 + 1e-4);
+                assert!(fabsf(calc_t(r, r_0).unwrap() - t) < 0.005 / r_0.r_0() as f32 * 260_f32);
+            }
+        }
+    }
+
+    #[test]
+    fn range_limits() {
+        // IEC 60751 values at -200°C and 850°C, rounded beyond the curve
+        assert_eq!(calc_t(18.52, RTDType::PT100).unwrap(), -200_f32);
+        assert!(fabsf(calc_t(390.48, RTDType::PT100).unwrap() - 850_f32) < 0.01);
+        assert_eq!(calc_t(390.485, RTDType::PT100).unwrap(), 850_f32);
+        assert_eq!(calc_t_f64(185.20, RTDType::PT1000).unwrap(), -200_f64);
+        assert!(matches!(calc_t(18.51, RTDType::PT100), Err(Error::OutOfRange { .. })));
+        assert!(matches!(solve_t(390.5, RTDType::PT100, 1e-4), Err(Error::OutOfRange { .. })));
+
+        // the temperature limits are exact in both directions
+        for sensor in [RTDType::PT100, RTDType::PT1000] {
+            for t in [-200_f32, 850_f32] {
+                assert!(fabsf(calc_t(calc_r(t, sensor).unwrap(), sensor).unwrap() - t) < 1e-3);
+            }
+        }
+        assert!(matches!(calc_r(850.5, RTDType::PT100), Err(Error::OutOfRange { .. })));
+        assert!(matches!(calc_r_f64(-200.5, RTDType::PT100), Err(Error::OutOfRange { .. })));
+    }
+
+    #[test]
+    fn correction_coefficient_order() {
+        // coefficients are ordered from the highest power down to the constant
+        let poly: Polynomial = [0_f32, 0_f32, 0_f32, 2_f32, -3_f32, 5_f32];
+        assert_eq!(poly_correction(4_f32, poly), 2_f32 * 16_f32 - 3_f32 * 4_f32 + 5_f32);
+        // R(-100°C) = 60.2558 Ω, the quadratic solution alone is off by 0.2 K
+        assert!(fabsf(calc_t(60.2558, RTDType::PT100).unwrap() + 100_f32) < 1e-3);
+    }
+
+    #[test]
+    fn derived_correction() {
+        // published polynomial for the IEC 60751 curve (UliEngineering)
+        #[allow(clippy::excessive_precision)]
+        let published: Polynomial = [1.51892983e-10, -2.85842067e-08, -5.34227299e-06,
+            1.80282972e-03, -1.61875985e-01, 4.84112370e+00];
+        let mut r = 18.6_f32;
+        while r < 100_f32 {
+            let derived = poly_correction(r, RTDCorrection::ALPHA_385);
+            assert!(fabsf(derived - poly_correction(r, published)) < 1e-4);
+            r += 0.5;
+        }
+    }
+
+    #[test]
+    fn temperature_solver_round_trip() {
+        for r_0 in [RTDType::PT100, RTDType::PT200, RTDType::PT500, RTDType::PT1000] {
+            // f32 cannot resolve µΩ: its spacing at R(850°C) is 1e-7 of R, 30 µΩ for a PT100
+            let tolerance = r_0.r_0() as f32 * 1e-6;
+            for t in (-200..=850).step_by(5) {
+                let r = calc_r(t as f32, r_0).unwrap();
+                let solution = solve_t(r, r_0, tolerance).unwrap();
+                assert!(fabsf(solution.residual) <= tolerance);
+                assert!(fabsf(calc_r(solution.t, r_0).unwrap() - r) <= tolerance);
+                assert!(fabsf(solution.t - t as f32) < 1e-3);
+
+                // within 1 µΩ in double precision
+                let r = calc_r_f64(t as f64, r_0).unwrap();
+                let solution = solve_t_f64(r, r_0, 1e-6).unwrap();
+                assert!(solution.residual.abs() <= 1e-6);
+                assert!(( calc_r_f64(solution.t, r_0).unwrap() - r ).abs() <= 1e-6);
+            }
+        }
+    }
+
+    #[test]
+    fn temperature_solver_out_of_bounds() {
+        assert!(matches!(solve_t(10_f32, RTDType::PT100, 1e-4), Err(Error::OutOfRange { .. })));
+        assert!(matches!(solve_t(400_f32, RTDType::PT100, 1e-4), Err(Error::OutOfRange { .. })));
+    }
+
+    #[test]
+    fn error_context() {
+        extern crate std;
+        use std::string::ToString;
+
+        let error = calc_r(900_f32, RTDType::PT100).unwrap_err();
+        assert_eq!(error, Error::OutOfRange {
+            quantity: Quantity::Temperature,
+            value: 900_f64,
+            min: -200_f64,
+            max: 850_f64,
+        });
+        assert_eq!(error.to_string(), "temperature of 900 °C out of range -200 °C to 850 °C");
+        assert!(matches!(
+            calc_t(10_f32, RTDType::PT100),
+            Err(Error::OutOfRange { quantity: Quantity::Resistance, .. }),
+        ));
+        assert_eq!(calc_t(f32::NAN, RTDType::PT100).unwrap_err(), Error::NotFinite { quantity: Quantity::Resistance });
+
+        assert_eq!(conv_d_val_to_r(256, 400, ADCRes::B8, 1).unwrap_err(), Error::AdcOverrange { d_val: 256, max: 255 });
+        assert_eq!(conv_d_val_to_r(255, 400, ADCRes::B8, 1).unwrap_err(), Error::AdcOverrange { d_val: 255, max: 255 });
+        assert_eq!(
+            conv_d_val_to_r_f64(16_777_215, 400, ADCRes::B24, 1).unwrap_err(),
+            Error::AdcOverrange { d_val: 16_777_215, max: 16_777_215 },
+        );
+        assert!(conv_d_val_to_r(254, 400, ADCRes::B8, 1).is_ok());
+        assert_eq!(conv_d_val_to_r(0, 400, ADCRes::B8, 1).unwrap_err(), Error::AdcUnderrange { d_val: 0, min: 0 });
+        assert_eq!(conv_d_val_to_r_f64(0, 400, ADCRes::B8, 1).unwrap_err(), Error::AdcUnderrange { d_val: 0, min: 0 });
+        assert!(conv_d_val_to_r(1, 400, ADCRes::B8, 1).is_ok());
+        assert_eq!(conv_d_val_to_r(100, 400, ADCRes::B8, 0).unwrap_err(), Error::ZeroGain);
+        assert_eq!(conv_d_val_to_r_f64(100, 400, ADCRes::B8, 0).unwrap_err(), Error::ZeroGain);
+    }
+
+    #[test]
+    fn calibrated_coefficients() {
+        // coefficients of an individually calibrated PT100
+        let sensor = CvdCoefficients::new(100.012, 3.9102e-3, -5.8021e-7, -4.2735e-12);
+        for t in [-195.8_f32, -38.83, 0.0, 0.01, 156.6, 419.53] {
+            let r = sensor.calc_r(t).unwrap();
+            assert!(fabsf(sensor.calc_t(r).unwrap() - t) < 1e-3);
+        }
+        assert!(fabsf(sensor.calc_r(0_f32).unwrap() - 100.012) < 1e-6);
+
+        // the coefficients are kept in double precision
+        let r = sensor.calc_r_f64(156.6).unwrap();
+        assert!(( sensor.calc_t_f64(r).unwrap() - 156.6 ).abs() < 1e-9);
+        let standard = CvdCoefficients::from(RTDType::PT100);
+        assert_eq!(standard.calc_r_f64(420.5).unwrap(), calc_r_f64(420.5, RTDType::PT100).unwrap());
+    }
+
+    #[test]
+    fn arbitrary_r_0() {
+        for r_0 in [RTDType::PT10, RTDType::PT50, RTDType::PT2000, RTDType::new(100.03), RTDType::new(487.5)] {
+            for t in (-200..=850).step_by(10) {
+                let r = calc_r(t as f32, r_0).unwrap();
+                assert!(fabsf(calc_t(r, r_0).unwrap() - t as f32) < 2e-3);
+            }
+        }
+        assert!(matches!(calc_r(0_f32, RTDType::new(0_f64)), Err(Error::NonexistentType)));
+        assert!(matches!(calc_t(100_f32, RTDType::new(-100_f64)), Err(Error::NonexistentType)));
+    }
+
+    #[test]
+    fn alternative_curves() {
+        for curve in [Curve::Alpha3916, Curve::Alpha3920, Curve::Alpha3926] {
+            let sensor = RTDType::PT100.with_curve(curve);
+            let r_100 = calc_r(100_f32, sensor).unwrap();
+            assert!(fabsf(r_100 - calc_r(100_f32, RTDType::PT100).unwrap()) > 0.5);
+            for t in (-200..=850).step_by(10) {
+                let r = calc_r(t as f32, sensor).unwrap();
+                assert!(fabsf(calc_t(r, sensor).unwrap() - t as f32) < 2e-3);
+            }
+        }
+        // R(100°C) = R0 · (1 + 100 · α)
+        let sensor = RTDType::PT1000.with_curve(Curve::Alpha3926);
+        assert!(fabsf(calc_r(100_f32, sensor).unwrap() - 1392.6) < 0.1);
+    }
+
+    #[test]
+    fn generic_sensors() {
+        fn check(sensor: &impl ResistiveSensor) {
+            let (t_min, t_max) = sensor.valid_range();
+            let mut t = t_min;
+            while t <= t_max {
+                let r = sensor.resistance_at(t).unwrap();
+                assert!(fabsf(sensor.temperature_at(r).unwrap() - t) < 2e-3);
+
+                // compare with a numerical derivative
+                let (t_lo, t_hi) = (( t - 0.5 ).max(t_min), ( t + 0.5 ).min(t_max));
+                let dr_dt = ( sensor.resistance_at(t_hi).unwrap() - sensor.resistance_at(t_lo).unwrap() ) / ( t_hi - t_lo );
+                assert!(fabsf(sensor.sensitivity_at(t).unwrap() / dr_dt - 1_f32) < 1e-3);
+                t += 10_f32;
+            }
+            assert!(sensor.resistance_at(t_max + 1_f32).is_err());
+        }
+
+        check(&RTDType::PT100);
+        check(&RTDType::PT1000.with_curve(Curve::Alpha3920));
+        check(&CvdCoefficients::new(100.012, 3.9102e-3, -5.8021e-7, -4.2735e-12));
+        check(&nickel::NiType::NI1000);
+        check(&nickel::NiType::NI1000_TK5000);
+        check(&copper::CuType::CU50);
+    }
+
+    #[test]
+    fn double_precision_round_trip() {
+        for r_0 in [RTDType::PT100, RTDType::PT1000, RTDType::new(100.0317), RTDType::PT100.with_curve(Curve::Alpha3926)] {
+            let mut t = -200_f64;
+            while t <= 850_f64 {
+                let r = calc_r_f64(t, r_0).unwrap();
+                let t_calc = calc_t_f64(r, r_0).unwrap();
+                assert!(( t_calc - t ).abs() < 1e-6);
+                assert!(( calc_r_f64(t_calc, r_0).unwrap() - r ).abs() < 1e-6);
+                t += 0.25;
+            }
+        }
+        let r = conv_d_val_to_r_f64(4_194_304, 400, ADCRes::B24, 1).unwrap();
+        assert!(( calc_t_f64(r, RTDType::PT100).unwrap() - 1.52508e-5 ).abs() < 1e-9);
+    }
+}
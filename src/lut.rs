@@ -0,0 +1,161 @@
+//! Lookup tables for resistance to temperature conversion without square roots or iteration.
+//!
+//! A [`Lut`] holds the temperatures at `N` equally spaced resistances between R(t_min) and
+//! R(t_max). It is generated by `const fn`, so it can be placed in flash as a `static`:
+//!
+//! ```rust,ignore
+//! static PT100_LUT: Lut<256> = Lut::new(RTDType::PT100, -200.0, 850.0);
+//!
+//! let t = PT100_LUT.lookup(r, Interpolation::Cubic)?;
+//! ```
+//!
+//! The lookup only needs a few multiplications. For the full range of -200–850°C with 256 entries
+//! the interpolation error against [`calc_t_f64`](crate::calc_t_f64) is below 2 mK for linear and
+//! below 0.2 mK for cubic interpolation, for each of the `PT10`–`PT2000` presets with each curve.
+//! The latter is limited by the resolution of `f32` near 850°C.
+
+use crate::{
+    cvd_dr_dt,
+    cvd_r,
+    Error,
+    Quantity,
+    RTDType,
+};
+
+/// Interpolation between the entries of a lookup table.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Interpolation {
+    /// Linear interpolation between the two neighbouring entries.
+    Linear,
+    /// Cubic interpolation through the four nearest entries.
+    Cubic,
+}
+
+/// Resistance to temperature lookup table with `N` entries.
+#[derive(Debug, Clone, Copy)]
+pub struct Lut<const N: usize> {
+    /// Resistance of the first entry in Ω.
+    r_start: f32,
+    /// Resistance step between entries in Ω.
+    r_step: f32,
+    /// Temperature of each entry in °C.
+    t: [f32; N],
+}
+
+impl<const N: usize> Lut<N> {
+    /// Generate a lookup table for a sensor type covering `t_min`–`t_max` in °C.
+    ///
+    /// Panics (at compile time when used in a constant) if the range is not within -200–850°C or
+    /// the table has less than four entries.
+    pub const fn new(sensor: RTDType, t_min: f32, t_max: f32) -> Self {
+        assert!(N >= 4, "lookup table needs at least four entries");
+        assert!(-200_f32 <= t_min && t_min < t_max && t_max <= 850_f32, "invalid temperature range");
+        assert!(sensor.r_0() > 0_f64, "invalid nominal resistance");
+
+        let (a, b, c) = sensor.curve().coefficients();
+        let r_0 = sensor.r_0();
+        // tabulate at exactly the resistances the lookup assumes for the f32 start and step
+        let r_start = cvd_r(t_min as f64, r_0, a, b, c) as f32 as f64;
+        let r_step = ( ( cvd_r(t_max as f64, r_0, a, b, c) - r_start ) / ( N - 1 ) as f64 ) as f32 as f64;
+
+        let mut t = [0_f32; N];
+        let mut i = 0;
+        while i < N {
+            let r = r_start + i as f64 * r_step;
+            // Newton–Raphson iteration from the linear approximation
+            let mut t_i = ( r / r_0 - 1_f64 ) / a;
+            let mut n = 0;
+            while n < 16 {
+                t_i -= ( cvd_r(t_i, r_0, a, b, c) - r ) / cvd_dr_dt(t_i, r_0, a, b, c);
+                n += 1;
+            }
+            t[i] = t_i as f32;
+            i += 1;
+        }
+
+        Lut { r_start: r_start as f32, r_step: r_step as f32, t }
+    }
+
+    /// Look up the temperature in °C for a resistance value in Ω.
+    ///
+    /// Allowed resistance range: the range covered by the table.
+    pub fn lookup(&self, r: f32, interpolation: Interpolation) -> Result<f32, Error> {
+        let x = ( r - self.r_start ) / self.r_step;
+        if !( x >= 0_f32 && x <= ( N - 1 ) as f32 ) {
+            let (r_min, r_max) = self.range();
+            return Err(Error::out_of_range(Quantity::Resistance, r as f64, r_min as f64, r_max as f64));
+        }
+        let i = ( x as usize ).min(N - 2);
+        let t = &self.t;
+
+        match interpolation {
+            Interpolation::Linear => {
+                let u = x - i as f32;
+                Ok(t[i] + u * ( t[i + 1] - t[i] ))
+            },
+            Interpolation::Cubic => {
+                // four nodes j..=j+3 around the interval, shifted at the ends of the table
+                let j = i.saturating_sub(1).min(N - 4);
+                let s = x - j as f32;
+                // Newton form with forward differences, which are small compared to t
+                let d_1 = t[j + 1] - t[j];
+                let d_2 = t[j + 2] - 2_f32 * t[j + 1] + t[j];
+                let d_3 = t[j + 3] - 3_f32 * t[j + 2] + 3_f32 * t[j + 1] - t[j];
+                Ok(t[j] + s * ( d_1 + ( s - 1_f32 ) * ( d_2 / 2_f32 + ( s - 2_f32 ) * d_3 / 6_f32 ) ))
+            },
+        }
+    }
+
+    /// Range of resistances covered by the table in Ω.
+    pub fn range(&self) -> (f32, f32) {
+        (self.r_start, self.r_start + ( N - 1 ) as f32 * self.r_step)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        calc_r_f64,
+        calc_t_f64,
+        Curve,
+    };
+
+    #[test]
+    fn interpolation_error() {
+        for preset in [
+            RTDType::PT10,
+            RTDType::PT50,
+            RTDType::PT100,
+            RTDType::PT200,
+            RTDType::PT500,
+            RTDType::PT1000,
+            RTDType::PT2000,
+        ] {
+            for curve in [Curve::Alpha385, Curve::Alpha3916, Curve::Alpha3920, Curve::Alpha3926] {
+                let sensor = preset.with_curve(curve);
+                let lut: Lut<256> = Lut::new(sensor, -200_f32, 850_f32);
+                let mut t = -199.9;
+                while t < 850_f64 {
+                    let r = calc_r_f64(t, sensor).unwrap() as f32;
+                    let t_exact = calc_t_f64(r as f64, sensor).unwrap();
+                    let linear = lut.lookup(r, Interpolation::Linear).unwrap();
+                    let cubic = lut.lookup(r, Interpolation::Cubic).unwrap();
+                    assert!(( linear as f64 - t_exact ).abs() < 2e-3);
+                    assert!(( cubic as f64 - t_exact ).abs() < 2e-4);
+                    t += 0.3;
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn table_limits() {
+        const LUT: Lut<16> = Lut::new(RTDType::PT100, 0_f32, 100_f32);
+        let (r_min, r_max) = LUT.range();
+        assert!(( LUT.lookup(r_min, Interpolation::Cubic).unwrap() ).abs() < 1e-4);
+        assert!(( LUT.lookup(r_max, Interpolation::Linear).unwrap() - 100_f32 ).abs() < 1e-4);
+        assert!(matches!(LUT.lookup(r_min - 0.01, Interpolation::Linear), Err(Error::OutOfRange { .. })));
+        assert!(matches!(LUT.lookup(r_max + 0.01, Interpolation::Cubic), Err(Error::OutOfRange { .. })));
+    }
+}
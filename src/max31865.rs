@@ -0,0 +1,641 @@
+//! Driver for the MAX31865 RTD-to-digital converter, based on the `embedded-hal` 1.0 SPI traits.
+//!
+//! Requires the `max31865` feature. The converter measures the ratio of the RTD to the reference
+//! resistor with 15 bit, the resistance is R = code · R_ref / 32768. It is converted to
+//! temperature with [`calc_t`].
+//!
+//! ```rust,ignore
+//! let mut max = Max31865::new(spi, 430, RTDType::PT100);
+//! max.configure(Config { wires: Wires::Three, filter: Filter::Hz50, bias: true, auto_convert: true })?;
+//! let t = max.read_temperature()?;
+//! ```
+//!
+//! The SPI device has to be configured for mode 1 or 3 and at most 5 MHz.
+//!
+//! With the `async` feature, `asynch::Max31865` awaits the SPI transfers and the DRDY pin
+//! through the `embedded-hal-async` traits instead of blocking.
+
+use core::{
+    convert::Infallible,
+    fmt,
+};
+
+use embedded_hal::{
+    delay::DelayNs,
+    spi::{
+        Operation,
+        SpiDevice,
+    },
+};
+
+use crate::{
+    calc_t,
+    RTDType,
+};
+
+const REG_CONFIG: u8 = 0x00;
+const REG_RTD: u8 = 0x01;
+const REG_HIGH_FAULT_THRESHOLD: u8 = 0x03;
+const REG_FAULT_STATUS: u8 = 0x07;
+/// Register addresses are written with the MSB set.
+const WRITE: u8 = 0x80;
+
+const CONFIG_BIAS: u8 = 1 << 7;
+const CONFIG_AUTO_CONVERT: u8 = 1 << 6;
+const CONFIG_ONE_SHOT: u8 = 1 << 5;
+const CONFIG_THREE_WIRE: u8 = 1 << 4;
+const CONFIG_FAULT_DETECTION: u8 = 0b11 << 2;
+const CONFIG_AUTOMATIC_FAULT_DETECTION: u8 = 0b01 << 2;
+const CONFIG_FAULT_CLEAR: u8 = 1 << 1;
+const CONFIG_FILTER_50HZ: u8 = 1 << 0;
+
+/// Settling time of the input filter after the bias is switched on in ms.
+const BIAS_SETTLING_MS: u32 = 10;
+/// Number of status polls while waiting for the automatic fault detection.
+const FAULT_DETECTION_POLLS: u32 = 10;
+
+/// Errors of the driver.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Error<E, P = Infallible> {
+    /// Error of the SPI bus.
+    Spi(E),
+    /// Error of the DRDY pin.
+    Pin(P),
+    /// The converter flagged a fault, see [`FaultStatus`].
+    Fault(FaultStatus),
+    /// The automatic fault detection did not finish.
+    Timeout,
+    /// The resistance could not be converted to temperature.
+    Conversion(crate::Error),
+}
+
+impl<E: fmt::Debug, P: fmt::Debug> fmt::Display for Error<E, P> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::Spi(error) => write!(f, "SPI error: {error:?}"),
+            Error::Pin(error) => write!(f, "DRDY pin error: {error:?}"),
+            Error::Fault(status) => write!(f, "RTD fault, status {:#010b}", status.0),
+            Error::Timeout => write!(f, "automatic fault detection timed out"),
+            Error::Conversion(error) => write!(f, "conversion failed: {error}"),
+        }
+    }
+}
+
+impl<E: fmt::Debug, P: fmt::Debug> core::error::Error for Error<E, P> {
+    fn source(&self) -> Option<&(dyn core::error::Error + 'static)> {
+        match self {
+            Error::Conversion(error) => Some(error),
+            _ => None,
+        }
+    }
+}
+
+impl<E, P> From<crate::Error> for Error<E, P> {
+    fn from(error: crate::Error) -> Self {
+        Error::Conversion(error)
+    }
+}
+
+/// Connection of the RTD.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Wires {
+    Two,
+    Three,
+    Four,
+}
+
+/// Notch frequency of the mains filter.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Filter {
+    Hz50,
+    Hz60,
+}
+
+impl Filter {
+    /// Maximum duration of a one-shot conversion in ms.
+    const fn conversion_time_ms(&self) -> u32 {
+        match self {
+            Filter::Hz50 => 66,
+            Filter::Hz60 => 55,
+        }
+    }
+}
+
+/// Configuration of the converter.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Config {
+    pub wires: Wires,
+    pub filter: Filter,
+    /// Keep the bias voltage on. Required for continuous conversions.
+    pub bias: bool,
+    /// Convert continuously at the rate of the filter.
+    pub auto_convert: bool,
+}
+
+impl Config {
+    /// Value of the configuration register.
+    const fn bits(&self) -> u8 {
+        let mut bits = 0;
+        if self.bias {
+            bits |= CONFIG_BIAS;
+        }
+        if self.auto_convert {
+            bits |= CONFIG_AUTO_CONVERT;
+        }
+        if let Wires::Three = self.wires {
+            bits |= CONFIG_THREE_WIRE;
+        }
+        if let Filter::Hz50 = self.filter {
+            bits |= CONFIG_FILTER_50HZ;
+        }
+        bits
+    }
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Config { wires: Wires::Four, filter: Filter::Hz50, bias: false, auto_convert: false }
+    }
+}
+
+/// Content of the fault status register.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct FaultStatus(pub u8);
+
+impl FaultStatus {
+    /// No fault is flagged.
+    pub const fn is_empty(&self) -> bool {
+        self.0 & 0b1111_1100 == 0
+    }
+
+    /// The RTD code is above the high fault threshold, e.g. an open sensor.
+    pub const fn rtd_high_threshold(&self) -> bool {
+        self.0 & ( 1 << 7 ) != 0
+    }
+
+    /// The RTD code is below the low fault threshold, e.g. a shorted sensor.
+    pub const fn rtd_low_threshold(&self) -> bool {
+        self.0 & ( 1 << 6 ) != 0
+    }
+
+    /// REFIN- is above 0.85 · V_BIAS.
+    pub const fn refin_high(&self) -> bool {
+        self.0 & ( 1 << 5 ) != 0
+    }
+
+    /// REFIN- is below 0.85 · V_BIAS with FORCE- open.
+    pub const fn refin_low(&self) -> bool {
+        self.0 & ( 1 << 4 ) != 0
+    }
+
+    /// RTDIN- is below 0.85 · V_BIAS with FORCE- open.
+    pub const fn rtdin_low(&self) -> bool {
+        self.0 & ( 1 << 3 ) != 0
+    }
+
+    /// Over- or undervoltage at one of the inputs.
+    pub const fn overvoltage(&self) -> bool {
+        self.0 & ( 1 << 2 ) != 0
+    }
+}
+
+/// MAX31865 connected to an RTD and a reference resistor.
+#[derive(Debug)]
+pub struct Max31865<SPI> {
+    spi: SPI,
+    config: Config,
+    /// Reference resistance in Ω.
+    r_ref: u32,
+    sensor: RTDType,
+}
+
+impl<SPI: SpiDevice> Max31865<SPI> {
+    /// Create a driver for a converter with the reference resistor `r_ref` in Ω.
+    ///
+    /// The converter is not configured until [`Max31865::configure`] is called.
+    pub fn new(spi: SPI, r_ref: u32, sensor: RTDType) -> Self {
+        Max31865 { spi, config: Config::default(), r_ref, sensor }
+    }
+
+    /// Release the SPI device.
+    pub fn release(self) -> SPI {
+        self.spi
+    }
+
+    /// Write the configuration to the converter.
+    pub fn configure(&mut self, config: Config) -> Result<(), Error<SPI::Error>> {
+        self.write(REG_CONFIG, &[config.bits()])?;
+        self.config = config;
+        Ok(())
+    }
+
+    /// Set the fault thresholds as 15 bit RTD codes.
+    pub fn set_fault_thresholds(&mut self, low: u16, high: u16) -> Result<(), Error<SPI::Error>> {
+        self.write(REG_HIGH_FAULT_THRESHOLD, &thresholds(low, high))
+    }
+
+    /// Read the 15 bit RTD code of the last conversion.
+    ///
+    /// If the converter flags a fault, the fault status is read and returned as error.
+    pub fn read_code(&mut self) -> Result<u16, Error<SPI::Error>> {
+        let mut buf = [0_u8; 2];
+        self.read(REG_RTD, &mut buf)?;
+        match u16::from_be_bytes(buf) {
+            raw if raw & 1 != 0 => Err(Error::Fault(self.read_fault_status()?)),
+            raw => Ok(raw >> 1),
+        }
+    }
+
+    /// Read the resistance of the last conversion in Ω.
+    pub fn read_resistance(&mut self) -> Result<f32, Error<SPI::Error>> {
+        Ok(resistance(self.read_code()?, self.r_ref))
+    }
+
+    /// Read the temperature of the last conversion in °C, e.g. in continuous conversion mode.
+    pub fn read_temperature(&mut self) -> Result<f32, Error<SPI::Error>> {
+        Ok(calc_t(self.read_resistance()?, self.sensor)?)
+    }
+
+    /// Start a single conversion, wait for it and read the temperature in °C.
+    ///
+    /// If the bias is off, it is switched on for the conversion. Continuous conversions are stopped
+    /// for the conversion. The configuration is restored afterwards.
+    pub fn one_shot(&mut self, delay: &mut impl DelayNs) -> Result<f32, Error<SPI::Error>> {
+        let config = self.config.bits() & !CONFIG_AUTO_CONVERT;
+        if !self.config.bias {
+            self.write(REG_CONFIG, &[config | CONFIG_BIAS])?;
+            delay.delay_ms(BIAS_SETTLING_MS);
+        }
+        self.write(REG_CONFIG, &[config | CONFIG_BIAS | CONFIG_ONE_SHOT])?;
+        delay.delay_ms(self.config.filter.conversion_time_ms());
+        let result = self.read_temperature();
+        if config | CONFIG_BIAS != self.config.bits() {
+            self.write(REG_CONFIG, &[self.config.bits()])?;
+        }
+        result
+    }
+
+    /// Read the fault status register.
+    pub fn read_fault_status(&mut self) -> Result<FaultStatus, Error<SPI::Error>> {
+        let mut buf = [0_u8];
+        self.read(REG_FAULT_STATUS, &mut buf)?;
+        Ok(FaultStatus(buf[0]))
+    }
+
+    /// Clear the fault status.
+    pub fn clear_faults(&mut self) -> Result<(), Error<SPI::Error>> {
+        let config = self.config.bits() & !( CONFIG_ONE_SHOT | CONFIG_FAULT_DETECTION );
+        self.write(REG_CONFIG, &[config | CONFIG_FAULT_CLEAR])
+    }
+
+    /// Run the automatic fault detection cycle and read the fault status.
+    ///
+    /// Continuous conversions are stopped during the detection and restarted afterwards.
+    pub fn detect_faults(&mut self, delay: &mut impl DelayNs) -> Result<FaultStatus, Error<SPI::Error>> {
+        let config = self.config.bits() & ( CONFIG_THREE_WIRE | CONFIG_FILTER_50HZ );
+        self.write(REG_CONFIG, &[config | CONFIG_BIAS | CONFIG_AUTOMATIC_FAULT_DETECTION])?;
+        let mut done = false;
+        for _ in 0..FAULT_DETECTION_POLLS {
+            delay.delay_us(100);
+            let mut buf = [0_u8];
+            self.read(REG_CONFIG, &mut buf)?;
+            if buf[0] & CONFIG_FAULT_DETECTION == 0 {
+                done = true;
+                break;
+            }
+        }
+        let status = self.read_fault_status()?;
+        self.configure(self.config)?;
+        match done {
+            true => Ok(status),
+            false => Err(Error::Timeout),
+        }
+    }
+
+    fn read(&mut self, register: u8, buf: &mut [u8]) -> Result<(), Error<SPI::Error>> {
+        self.spi
+            .transaction(&mut [Operation::Write(&[register]), Operation::Read(buf)])
+            .map_err(Error::Spi)
+    }
+
+    fn write(&mut self, register: u8, data: &[u8]) -> Result<(), Error<SPI::Error>> {
+        self.spi
+            .transaction(&mut [Operation::Write(&[register | WRITE]), Operation::Write(data)])
+            .map_err(Error::Spi)
+    }
+}
+
+/// Content of the fault threshold registers for 15 bit RTD codes, high threshold first.
+fn thresholds(low: u16, high: u16) -> [u8; 4] {
+    let (low, high) = (low << 1, high << 1);
+    [( high >> 8 ) as u8, high as u8, ( low >> 8 ) as u8, low as u8]
+}
+
+/// Resistance in Ω of a 15 bit RTD code.
+fn resistance(code: u16, r_ref: u32) -> f32 {
+    code as f32 * r_ref as f32 / 32_768_f32
+}
+
+/// Async variant of the driver, based on the `embedded-hal-async` 1.0 traits.
+///
+/// The end of a conversion is awaited at the DRDY pin, so other tasks can run in the meantime.
+/// The pin never goes low if the converter is not powered, so wrap the futures in a timeout of
+/// the executor where this matters.
+#[cfg(feature = "async")]
+pub mod asynch {
+    use embedded_hal_async::{
+        delay::DelayNs,
+        digital::Wait,
+        spi::{
+            Operation,
+            SpiDevice,
+        },
+    };
+
+    use super::*;
+
+    /// MAX31865 connected to an RTD and a reference resistor, with its DRDY pin.
+    #[derive(Debug)]
+    pub struct Max31865<SPI, DRDY> {
+        spi: SPI,
+        drdy: DRDY,
+        config: Config,
+        /// Reference resistance in Ω.
+        r_ref: u32,
+        sensor: RTDType,
+    }
+
+    impl<SPI: SpiDevice, DRDY: Wait> Max31865<SPI, DRDY> {
+        /// Create a driver for a converter with the reference resistor `r_ref` in Ω.
+        ///
+        /// The converter is not configured until [`Max31865::configure`] is called.
+        pub fn new(spi: SPI, drdy: DRDY, r_ref: u32, sensor: RTDType) -> Self {
+            Max31865 { spi, drdy, config: Config::default(), r_ref, sensor }
+        }
+
+        /// Release the SPI device and the DRDY pin.
+        pub fn release(self) -> (SPI, DRDY) {
+            (self.spi, self.drdy)
+        }
+
+        /// Write the configuration to the converter.
+        pub async fn configure(&mut self, config: Config) -> Result<(), Error<SPI::Error, DRDY::Error>> {
+            self.write(REG_CONFIG, &[config.bits()]).await?;
+            self.config = config;
+            Ok(())
+        }
+
+        /// Set the fault thresholds as 15 bit RTD codes.
+        pub async fn set_fault_thresholds(&mut self, low: u16, high: u16) -> Result<(), Error<SPI::Error, DRDY::Error>> {
+            self.write(REG_HIGH_FAULT_THRESHOLD, &thresholds(low, high)).await
+        }
+
+        /// Read the 15 bit RTD code of the last conversion.
+        ///
+        /// If the converter flags a fault, the fault status is read and returned as error.
+        pub async fn read_code(&mut self) -> Result<u16, Error<SPI::Error, DRDY::Error>> {
+            let mut buf = [0_u8; 2];
+            self.read(REG_RTD, &mut buf).await?;
+            match u16::from_be_bytes(buf) {
+                raw if raw & 1 != 0 => Err(Error::Fault(self.read_fault_status().await?)),
+                raw => Ok(raw >> 1),
+            }
+        }
+
+        /// Wait for the next conversion and read its resistance in Ω.
+        pub async fn read_resistance(&mut self) -> Result<f32, Error<SPI::Error, DRDY::Error>> {
+            self.drdy.wait_for_low().await.map_err(Error::Pin)?;
+            Ok(resistance(self.read_code().await?, self.r_ref))
+        }
+
+        /// Wait for the next conversion and read its temperature in °C, e.g. in continuous
+        /// conversion mode.
+        pub async fn read_temperature(&mut self) -> Result<f32, Error<SPI::Error, DRDY::Error>> {
+            Ok(calc_t(self.read_resistance().await?, self.sensor)?)
+        }
+
+        /// Start a single conversion, wait for it and read the temperature in °C.
+        ///
+        /// If the bias is off, it is switched on for the conversion. Continuous conversions are
+        /// stopped for the conversion. The configuration is restored afterwards.
+        pub async fn one_shot(&mut self, delay: &mut impl DelayNs) -> Result<f32, Error<SPI::Error, DRDY::Error>> {
+            let config = self.config.bits() & !CONFIG_AUTO_CONVERT;
+            if !self.config.bias {
+                self.write(REG_CONFIG, &[config | CONFIG_BIAS]).await?;
+                delay.delay_ms(BIAS_SETTLING_MS).await;
+            }
+            self.write(REG_CONFIG, &[config | CONFIG_BIAS | CONFIG_ONE_SHOT]).await?;
+            let result = self.read_temperature().await;
+            if config | CONFIG_BIAS != self.config.bits() {
+                self.write(REG_CONFIG, &[self.config.bits()]).await?;
+            }
+            result
+        }
+
+        /// Read the fault status register.
+        pub async fn read_fault_status(&mut self) -> Result<FaultStatus, Error<SPI::Error, DRDY::Error>> {
+            let mut buf = [0_u8];
+            self.read(REG_FAULT_STATUS, &mut buf).await?;
+            Ok(FaultStatus(buf[0]))
+        }
+
+        /// Clear the fault status.
+        pub async fn clear_faults(&mut self) -> Result<(), Error<SPI::Error, DRDY::Error>> {
+            let config = self.config.bits() & !( CONFIG_ONE_SHOT | CONFIG_FAULT_DETECTION );
+            self.write(REG_CONFIG, &[config | CONFIG_FAULT_CLEAR]).await
+        }
+
+        /// Run the automatic fault detection cycle and read the fault status.
+        ///
+        /// Continuous conversions are stopped during the detection and restarted afterwards.
+        pub async fn detect_faults(&mut self, delay: &mut impl DelayNs) -> Result<FaultStatus, Error<SPI::Error, DRDY::Error>> {
+            let config = self.config.bits() & ( CONFIG_THREE_WIRE | CONFIG_FILTER_50HZ );
+            self.write(REG_CONFIG, &[config | CONFIG_BIAS | CONFIG_AUTOMATIC_FAULT_DETECTION]).await?;
+            let mut done = false;
+            for _ in 0..FAULT_DETECTION_POLLS {
+                delay.delay_us(100).await;
+                let mut buf = [0_u8];
+                self.read(REG_CONFIG, &mut buf).await?;
+                if buf[0] & CONFIG_FAULT_DETECTION == 0 {
+                    done = true;
+                    break;
+                }
+            }
+            let status = self.read_fault_status().await?;
+            self.configure(self.config).await?;
+            match done {
+                true => Ok(status),
+                false => Err(Error::Timeout),
+            }
+        }
+
+        async fn read(&mut self, register: u8, buf: &mut [u8]) -> Result<(), Error<SPI::Error, DRDY::Error>> {
+            self.spi
+                .transaction(&mut [Operation::Write(&[register]), Operation::Read(buf)])
+                .await
+                .map_err(Error::Spi)
+        }
+
+        async fn write(&mut self, register: u8, data: &[u8]) -> Result<(), Error<SPI::Error, DRDY::Error>> {
+            self.spi
+                .transaction(&mut [Operation::Write(&[register | WRITE]), Operation::Write(data)])
+                .await
+                .map_err(Error::Spi)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    extern crate std;
+
+    use super::*;
+    use embedded_hal_mock::eh1::{
+        delay::NoopDelay,
+        spi::{
+            Mock,
+            Transaction,
+        },
+    };
+    use libm::fabsf;
+    use std::{
+        string::ToString,
+        vec,
+    };
+
+    /// Expected transactions of a register read.
+    fn read(register: u8, response: &[u8]) -> [Transaction<u8>; 4] {
+        [
+            Transaction::transaction_start(),
+            Transaction::write_vec(vec![register]),
+            Transaction::read_vec(response.to_vec()),
+            Transaction::transaction_end(),
+        ]
+    }
+
+    /// Expected transactions of a register write.
+    fn write(register: u8, data: &[u8]) -> [Transaction<u8>; 4] {
+        [
+            Transaction::transaction_start(),
+            Transaction::write_vec(vec![register | WRITE]),
+            Transaction::write_vec(data.to_vec()),
+            Transaction::transaction_end(),
+        ]
+    }
+
+    #[test]
+    fn continuous_conversion() {
+        // 138.5 Ω ≙ 100°C with a 430 Ω reference, the code is left-aligned in the registers
+        let code = ( 138.5055_f32 / 430_f32 * 32_768_f32 ) as u16;
+        let expectations = [
+            write(REG_CONFIG, &[0b1101_0001]),
+            read(REG_RTD, &( code << 1 ).to_be_bytes()),
+        ].concat();
+        let mut max = Max31865::new(Mock::new(&expectations), 430, RTDType::PT100);
+        max.configure(Config { wires: Wires::Three, filter: Filter::Hz50, bias: true, auto_convert: true }).unwrap();
+        assert!(fabsf(max.read_temperature().unwrap() - 100_f32) < 0.05);
+        max.release().done();
+    }
+
+    #[test]
+    fn one_shot() {
+        let expectations = [
+            write(REG_CONFIG, &[0b0000_0000]),
+            write(REG_CONFIG, &[0b1000_0000]),
+            write(REG_CONFIG, &[0b1010_0000]),
+            read(REG_RTD, &[0x3B, 0x88]),
+            write(REG_CONFIG, &[0b0000_0000]),
+        ].concat();
+        let mut max = Max31865::new(Mock::new(&expectations), 430, RTDType::PT100);
+        max.configure(Config { filter: Filter::Hz60, ..Config::default() }).unwrap();
+        // 7620 · 430 Ω / 32768 = 99.99 Ω
+        assert!(fabsf(max.one_shot(&mut NoopDelay::new()).unwrap()) < 0.05);
+        max.release().done();
+    }
+
+    #[test]
+    fn one_shot_restores_continuous_conversion() {
+        let expectations = [
+            write(REG_CONFIG, &[0b1101_0001]),
+            write(REG_CONFIG, &[0b1011_0001]),
+            read(REG_RTD, &[0x3B, 0x88]),
+            write(REG_CONFIG, &[0b1101_0001]),
+        ].concat();
+        let mut max = Max31865::new(Mock::new(&expectations), 430, RTDType::PT100);
+        max.configure(Config { wires: Wires::Three, filter: Filter::Hz50, bias: true, auto_convert: true }).unwrap();
+        assert!(fabsf(max.one_shot(&mut NoopDelay::new()).unwrap()) < 0.05);
+        max.release().done();
+    }
+
+    #[test]
+    fn faults() {
+        let expectations = [
+            read(REG_RTD, &[0xFF, 0xFF]),
+            read(REG_FAULT_STATUS, &[0b1000_0100]),
+            write(REG_CONFIG, &[0b1000_0101]),
+            read(REG_CONFIG, &[0b1000_0101]),
+            read(REG_CONFIG, &[0b1000_0001]),
+            read(REG_FAULT_STATUS, &[0b0000_1000]),
+            write(REG_CONFIG, &[0b0000_0001]),
+            write(REG_CONFIG, &[0b0000_0011]),
+        ].concat();
+        let mut max = Max31865::new(Mock::new(&expectations), 430, RTDType::PT100);
+
+        let error = max.read_temperature().unwrap_err();
+        assert_eq!(error.to_string(), "RTD fault, status 0b10000100");
+        let status = match error {
+            Error::Fault(status) => status,
+            error => panic!("unexpected error {error:?}"),
+        };
+        assert!(status.rtd_high_threshold() && status.overvoltage() && !status.rtd_low_threshold());
+
+        let status = max.detect_faults(&mut NoopDelay::new()).unwrap();
+        assert!(status.rtdin_low() && !status.is_empty());
+        max.clear_faults().unwrap();
+        max.release().done();
+
+        let error: Error<Infallible> = crate::Error::NonexistentType.into();
+        assert!(core::error::Error::source(&error).is_some());
+    }
+
+    #[cfg(feature = "async")]
+    #[test]
+    fn async_one_shot() {
+        use crate::executor::block_on;
+        use embedded_hal_mock::eh1::digital::{
+            Mock as PinMock,
+            State,
+            Transaction as PinTransaction,
+        };
+
+        let expectations = [
+            write(REG_CONFIG, &[0b0000_0001]),
+            write(REG_CONFIG, &[0b1000_0001]),
+            write(REG_CONFIG, &[0b1010_0001]),
+            read(REG_RTD, &[0x3B, 0x88]),
+            write(REG_CONFIG, &[0b0000_0001]),
+            read(REG_RTD, &[0xFF, 0xFF]),
+            read(REG_FAULT_STATUS, &[0b1000_0000]),
+            write(REG_CONFIG, &[0b1100_0001]),
+            write(REG_CONFIG, &[0b1010_0001]),
+            read(REG_RTD, &[0x3B, 0x88]),
+            write(REG_CONFIG, &[0b1100_0001]),
+        ].concat();
+        let drdy = [(); 3].map(|()| PinTransaction::wait_for_state(State::Low));
+        let mut max = asynch::Max31865::new(Mock::new(&expectations), PinMock::new(&drdy), 430, RTDType::PT100);
+        block_on(async {
+            max.configure(Config::default()).await.unwrap();
+            assert!(fabsf(max.one_shot(&mut NoopDelay::new()).await.unwrap()) < 0.05);
+            match max.read_temperature().await {
+                Err(Error::Fault(status)) => assert!(status.rtd_high_threshold()),
+                result => panic!("unexpected result {result:?}"),
+            }
+            // continuous conversions are stopped for the one-shot and restarted afterwards
+            max.configure(Config { bias: true, auto_convert: true, ..Config::default() }).await.unwrap();
+            assert!(fabsf(max.one_shot(&mut NoopDelay::new()).await.unwrap()) < 0.05);
+        });
+        let (mut spi, mut drdy) = max.release();
+        spi.done();
+        drdy.done();
+    }
+}
@@ -0,0 +1,186 @@
+//! Calculation methods for nickel type RTD temperature sensors.
+//!
+//! The functions mirror [`calc_r`](crate::calc_r) and [`calc_t`](crate::calc_t) for platinum
+//! sensors. Resistance is calculated from
+//!
+//! R(t) = R0 · (1 + A·t + B·t² + D·t⁴ + F·t⁶)
+//!
+//! with the coefficients of DIN 43760 (α = 0.00618), of the Ni120 sensors common in US industry
+//! (α = 0.00672) or of the LG-Ni1000 "TK5000" sensors common in building automation (α = 0.005).
+//! Temperature is found by Newton–Raphson iteration.
+
+use libm::powf;
+
+use crate::{
+    newton_raphson,
+    Error,
+    Quantity,
+    ResistiveSensor,
+};
+
+/// Curve standards for nickel RTDs, named after their mean temperature coefficient α between 0°C
+/// and 100°C.
+#[non_exhaustive]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum NiCurve {
+    /// α = 0.00618, DIN 43760. Allowed temperature range: -60–180°C.
+    Alpha618,
+    /// α = 0.00672, Ni120 (Edison curve 7). Allowed temperature range: -80–200°C.
+    Alpha672,
+    /// α = 0.005, LG-Ni1000 (TK5000). Allowed temperature range: -50–150°C.
+    Alpha500,
+}
+
+impl NiCurve {
+    /// Coefficients A, B, D and F of the curve.
+    pub const fn coefficients(&self) -> (f32, f32, f32, f32) {
+        match self {
+            NiCurve::Alpha618 => (5.485e-3, 6.650e-6, 2.805e-11, -2.000e-17),
+            // fitted to the Ni120 reference table with R(100°C) = 1.672 · R0
+            NiCurve::Alpha672 => (6.041e-3, 5.952e-6, 9.455e-11, -1.076e-15),
+            NiCurve::Alpha500 => (4.427e-3, 5.172e-6, 5.585e-12, 0_f32),
+        }
+    }
+
+    /// Allowed temperature range in °C.
+    pub const fn range(&self) -> (f32, f32) {
+        match self {
+            NiCurve::Alpha618 => (-60_f32, 180_f32),
+            NiCurve::Alpha672 => (-80_f32, 200_f32),
+            NiCurve::Alpha500 => (-50_f32, 150_f32),
+        }
+    }
+}
+
+/// Nickel RTD of arbitrary nominal resistance R0 at 0°C.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct NiType {
+    r_0: f64,
+    curve: NiCurve,
+}
+
+impl NiType {
+    pub const NI100: NiType = NiType::new(100_f64);
+    pub const NI120: NiType = NiType::new(120_f64).with_curve(NiCurve::Alpha672);
+    pub const NI1000: NiType = NiType::new(1000_f64);
+    pub const NI1000_TK5000: NiType = NiType::new(1000_f64).with_curve(NiCurve::Alpha500);
+
+    /// Create a sensor type with nominal resistance `r_0` at 0°C in Ω following DIN 43760.
+    pub const fn new(r_0: f64) -> Self {
+        NiType { r_0, curve: NiCurve::Alpha618 }
+    }
+
+    /// Select the curve standard of the sensor.
+    pub const fn with_curve(self, curve: NiCurve) -> Self {
+        NiType { curve, ..self }
+    }
+
+    /// Nominal resistance at 0°C in Ω.
+    pub const fn r_0(&self) -> f64 {
+        self.r_0
+    }
+
+    /// Curve standard of the sensor.
+    pub const fn curve(&self) -> NiCurve {
+        self.curve
+    }
+
+    /// Evaluate the curve without range checks.
+    fn r(&self, t: f32) -> f32 {
+        let (a, b, d, f) = self.curve.coefficients();
+        self.r_0 as f32 * ( 1_f32 + a * t + b * powf(t, 2_f32) + d * powf(t, 4_f32) + f * powf(t, 6_f32) )
+    }
+
+    /// Evaluate the derivative dR/dt of the curve.
+    fn dr_dt(&self, t: f32) -> f32 {
+        let (a, b, d, f) = self.curve.coefficients();
+        self.r_0 as f32 * ( a + 2_f32 * b * t + 4_f32 * d * powf(t, 3_f32) + 6_f32 * f * powf(t, 5_f32) )
+    }
+}
+
+/// Calculate resistance of nickel RTD for a specified temperature.
+///
+/// Allowed temperature range: depends on the curve, see [`NiCurve`].
+#[allow(dead_code)]
+pub fn calc_r(t: f32, r_0: NiType) -> Result<f32, Error> {
+    if !(r_0.r_0.is_finite() && r_0.r_0 > 0_f64) {
+        return Err(Error::NonexistentType);
+    }
+    let (t_min, t_max) = r_0.curve.range();
+    match t {
+        t if (t_min..=t_max).contains(&t) => Ok(r_0.r(t)),
+        t => Err(Error::out_of_range(Quantity::Temperature, t as f64, t_min as f64, t_max as f64)),
+    }
+}
+
+/// Calculate temperature of nickel RTD from resistance value.
+///
+/// The result is solved to within 1 ppm of R0. Allowed temperature range: depends on the curve, see
+/// [`NiCurve`].
+#[allow(dead_code)]
+pub fn calc_t(r: f32, r_0: NiType) -> Result<f32, Error> {
+    let (t_min, t_max) = r_0.curve.range();
+    let r_min = calc_r(t_min, r_0)?;
+    let r_max = calc_r(t_max, r_0)?;
+    if !(r_min..=r_max).contains(&r) {
+        return Err(Error::out_of_range(Quantity::Resistance, r as f64, r_min as f64, r_max as f64));
+    }
+
+    // start from the linear approximation
+    let (a, ..) = r_0.curve.coefficients();
+    let t = ( r / r_0.r_0 as f32 - 1_f32 ) / a;
+    let tolerance = r_0.r_0 as f32 * 1e-6;
+    newton_raphson(r, t, tolerance, |t| r_0.r(t), |t| r_0.dr_dt(t)).map(|solution| solution.t)
+}
+
+impl ResistiveSensor for NiType {
+    fn resistance_at(&self, t: f32) -> Result<f32, Error> {
+        calc_r(t, *self)
+    }
+
+    fn temperature_at(&self, r: f32) -> Result<f32, Error> {
+        calc_t(r, *self)
+    }
+
+    fn valid_range(&self) -> (f32, f32) {
+        self.curve.range()
+    }
+
+    fn sensitivity_at(&self, t: f32) -> Result<f32, Error> {
+        calc_r(t, *self)?;
+        Ok(self.dr_dt(t))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use libm::fabsf;
+
+    #[test]
+    fn resistance_calculation() {
+        // DIN 43760 reference values
+        assert!(fabsf(calc_r(0_f32, NiType::NI100).unwrap() - 100_f32) < 1e-4);
+        assert!(fabsf(calc_r(100_f32, NiType::NI100).unwrap() - 161.78) < 0.01);
+        // Ni120 reference values, α = 0.00672
+        assert!(fabsf(calc_r(0_f32, NiType::NI120).unwrap() - 120_f32) < 1e-4);
+        assert!(fabsf(calc_r(100_f32, NiType::NI120).unwrap() - 200.64) < 0.01);
+        assert!(matches!(calc_r(-90_f32, NiType::NI120), Err(Error::OutOfRange { .. })));
+        // LG-Ni1000 reference values
+        assert!(fabsf(calc_r(20_f32, NiType::NI1000_TK5000).unwrap() - 1090.6) < 0.1);
+        assert!(matches!(calc_r(200_f32, NiType::NI1000), Err(Error::OutOfRange { .. })));
+    }
+
+    #[test]
+    fn temperature_calculation() {
+        for sensor in [NiType::NI100, NiType::NI120, NiType::NI1000, NiType::NI1000_TK5000] {
+            let (t_min, t_max) = sensor.curve().range();
+            let mut t = t_min;
+            while t <= t_max {
+                let r = calc_r(t, sensor).unwrap();
+                assert!(fabsf(calc_t(r, sensor).unwrap() - t) < 1e-3);
+                t += 5_f32;
+            }
+        }
+    }
+}
@@ -0,0 +1,136 @@
+//! Self-heating of the sensing element by the excitation.
+//!
+//! The power P = I²·R dissipated in the element raises its temperature above that of the medium by
+//! ΔT = P / δ, where δ is the dissipation constant in mW/K. It depends on the construction of the
+//! sensor and on the medium, e.g. a few mW/K in still air and tens of mW/K in stirred water.
+//!
+//! The dissipation constant of an installed sensor can be measured with two excitation currents,
+//! see [`dissipation_constant`].
+
+use crate::{
+    Error,
+    Quantity,
+    ResistiveSensor,
+};
+
+/// Excitation of the sensor.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Excitation {
+    /// Excitation current in A.
+    Current(f32),
+    /// Power dissipated in the element in W.
+    Power(f32),
+}
+
+impl Excitation {
+    /// Power dissipated in the element of resistance `r` in W.
+    pub fn power(&self, r: f32) -> f32 {
+        match *self {
+            Excitation::Current(i) => i * i * r,
+            Excitation::Power(p) => p,
+        }
+    }
+}
+
+/// Calculate the temperature rise in K of an element of resistance `r` by the excitation, for a
+/// dissipation constant in mW/K.
+///
+/// The dissipation constant has to be positive and finite.
+#[allow(dead_code)]
+pub fn temperature_rise(r: f32, excitation: Excitation, dissipation: f32) -> Result<f32, Error> {
+    match dissipation {
+        d if d.is_finite() && d > 0_f32 => Ok(excitation.power(r) * 1e3 / d),
+        d => Err(Error::out_of_range(Quantity::DissipationConstant, d as f64, 0_f64, f64::INFINITY)),
+    }
+}
+
+/// Calculate temperature of the medium in °C from the resistance of the self-heated element, for a
+/// dissipation constant in mW/K.
+#[allow(dead_code)]
+pub fn calc_t(r: f32, excitation: Excitation, dissipation: f32, sensor: &impl ResistiveSensor) -> Result<f32, Error> {
+    let t = sensor.temperature_at(r)? - temperature_rise(r, excitation, dissipation)?;
+    match t {
+        t if t.is_finite() => Ok(t),
+        _ => Err(Error::NotFinite { quantity: Quantity::Temperature }),
+    }
+}
+
+/// Derive the dissipation constant in mW/K from the resistances `r_1` and `r_2` measured at the
+/// excitation currents `i_1` and `i_2` in A, at the same temperature of the medium.
+///
+/// The temperatures of the element differ by the difference of the temperature rises, so
+/// δ = (P_2 - P_1) / (t_2 - t_1).
+#[allow(dead_code)]
+pub fn dissipation_constant(
+    (i_1, r_1): (f32, f32),
+    (i_2, r_2): (f32, f32),
+    sensor: &impl ResistiveSensor,
+) -> Result<f32, Error> {
+    let (t_1, t_2) = (sensor.temperature_at(r_1)?, sensor.temperature_at(r_2)?);
+    let (p_1, p_2) = (Excitation::Current(i_1).power(r_1), Excitation::Current(i_2).power(r_2));
+    match ( p_2 - p_1 ) * 1e3 / ( t_2 - t_1 ) {
+        dissipation if dissipation.is_finite() && dissipation > 0_f32 => Ok(dissipation),
+        _ => Err(Error::InsufficientData), // the currents do not cause different temperature rises
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::RTDType;
+    use libm::fabsf;
+
+    /// Resistance of a self-heated element in a medium at `t` by fixed-point iteration.
+    fn heated_r(t: f32, i: f32, dissipation: f32, sensor: &RTDType) -> f32 {
+        let mut r = sensor.resistance_at(t).unwrap();
+        for _ in 0..8 {
+            r = sensor.resistance_at(t + temperature_rise(r, Excitation::Current(i), dissipation).unwrap()).unwrap();
+        }
+        r
+    }
+
+    #[test]
+    fn self_heating_correction() {
+        // PT100 at 25°C in still air, 2 mW/K
+        let sensor = RTDType::PT100;
+        let r = heated_r(25_f32, 1e-3, 2_f32, &sensor);
+        assert!(fabsf(sensor.temperature_at(r).unwrap() - 25.055) < 1e-3);
+        assert!(fabsf(calc_t(r, Excitation::Current(1e-3), 2_f32, &sensor).unwrap() - 25_f32) < 1e-3);
+
+        let p = Excitation::Current(1e-3).power(r);
+        assert!(fabsf(calc_t(r, Excitation::Power(p), 2_f32, &sensor).unwrap() - 25_f32) < 1e-3);
+    }
+
+    #[test]
+    fn invalid_dissipation_constant() {
+        let rise = |dissipation| temperature_rise(110_f32, Excitation::Current(1e-3), dissipation);
+        assert!(fabsf(rise(2_f32).unwrap() - 0.055) < 1e-6);
+        assert_eq!(rise(0_f32), Err(Error::OutOfRange {
+            quantity: Quantity::DissipationConstant,
+            value: 0_f64,
+            min: 0_f64,
+            max: f64::INFINITY,
+        }));
+        assert!(matches!(rise(-2_f32), Err(Error::OutOfRange { quantity: Quantity::DissipationConstant, .. })));
+        assert_eq!(rise(f32::NAN), Err(Error::NotFinite { quantity: Quantity::DissipationConstant }));
+        assert_eq!(rise(f32::INFINITY), Err(Error::NotFinite { quantity: Quantity::DissipationConstant }));
+
+        let sensor = RTDType::PT100;
+        assert!(matches!(
+            calc_t(110_f32, Excitation::Current(1e-3), -2_f32, &sensor),
+            Err(Error::OutOfRange { quantity: Quantity::DissipationConstant, .. }),
+        ));
+    }
+
+    #[test]
+    fn dissipation_from_two_currents() {
+        let sensor = RTDType::PT1000;
+        let (i_1, i_2) = (0.1e-3, 0.5e-3);
+        let (r_1, r_2) = (heated_r(60_f32, i_1, 4.5, &sensor), heated_r(60_f32, i_2, 4.5, &sensor));
+        let dissipation = dissipation_constant((i_1, r_1), (i_2, r_2), &sensor).unwrap();
+        assert!(fabsf(dissipation - 4.5) < 0.02);
+        assert!(fabsf(calc_t(r_2, Excitation::Current(i_2), dissipation, &sensor).unwrap() - 60_f32) < 5e-3);
+
+        assert!(matches!(dissipation_constant((i_1, r_1), (i_1, r_1), &sensor), Err(Error::InsufficientData)));
+    }
+}
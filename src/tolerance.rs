@@ -0,0 +1,127 @@
+//! Tolerance classes of platinum RTDs according to IEC 60751:2008.
+//!
+//! The tolerance of each class is ±(a + b·|t|) in °C. It is only defined within a temperature range
+//! that depends on the construction of the element. Besides the IEC classes AA, A, B and C the
+//! common 1/3 DIN and 1/10 DIN classes (a third and a tenth of class B) are included.
+
+use libm::fabsf;
+
+use crate::{
+    Error,
+    Quantity,
+    ResistiveSensor,
+};
+
+/// Tolerance class of a platinum RTD.
+#[non_exhaustive]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ToleranceClass {
+    /// ±(0.1 + 0.0017·|t|)°C
+    AA,
+    /// ±(0.15 + 0.002·|t|)°C
+    A,
+    /// ±(0.3 + 0.005·|t|)°C
+    B,
+    /// ±(0.6 + 0.01·|t|)°C
+    C,
+    /// ±1/3·(0.3 + 0.005·|t|)°C
+    ThirdDin,
+    /// ±1/10·(0.3 + 0.005·|t|)°C
+    TenthDin,
+}
+
+/// Construction of the sensing element.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Construction {
+    WireWound,
+    Film,
+}
+
+impl ToleranceClass {
+    /// Coefficients `(a, b)` of the tolerance ±(a + b·|t|)°C.
+    pub const fn coefficients(&self) -> (f32, f32) {
+        match self {
+            ToleranceClass::AA => (0.1, 0.0017),
+            ToleranceClass::A => (0.15, 0.002),
+            ToleranceClass::B => (0.3, 0.005),
+            ToleranceClass::C => (0.6, 0.01),
+            ToleranceClass::ThirdDin => (0.1, 0.005 / 3_f32),
+            ToleranceClass::TenthDin => (0.03, 0.0005),
+        }
+    }
+
+    /// Temperature range in °C within which the class is defined.
+    pub const fn range(&self, construction: Construction) -> (f32, f32) {
+        match (self, construction) {
+            (ToleranceClass::AA, Construction::WireWound) => (-50_f32, 250_f32),
+            (ToleranceClass::AA, Construction::Film) => (0_f32, 150_f32),
+            (ToleranceClass::A, Construction::WireWound) => (-100_f32, 450_f32),
+            (ToleranceClass::A, Construction::Film) => (-30_f32, 300_f32),
+            (ToleranceClass::B, Construction::WireWound) => (-196_f32, 600_f32),
+            (ToleranceClass::B, Construction::Film) => (-50_f32, 500_f32),
+            (ToleranceClass::C, Construction::WireWound) => (-196_f32, 600_f32),
+            (ToleranceClass::C, Construction::Film) => (-50_f32, 600_f32),
+            (ToleranceClass::ThirdDin, Construction::WireWound) => (-50_f32, 250_f32),
+            (ToleranceClass::ThirdDin, Construction::Film) => (0_f32, 150_f32),
+            (ToleranceClass::TenthDin, _) => (0_f32, 100_f32),
+        }
+    }
+}
+
+/// Calculate the tolerance band ±°C of a tolerance class at a specified temperature.
+///
+/// Allowed temperature range: depends on class and construction, see [`ToleranceClass::range`].
+#[allow(dead_code)]
+pub fn tolerance_t(class: ToleranceClass, construction: Construction, t: f32) -> Result<f32, Error> {
+    let (t_min, t_max) = class.range(construction);
+    let (a, b) = class.coefficients();
+    match t {
+        t if (t_min..=t_max).contains(&t) => Ok(a + b * fabsf(t)),
+        t => Err(Error::out_of_range(Quantity::Temperature, t as f64, t_min as f64, t_max as f64)),
+    }
+}
+
+/// Calculate the tolerance band ±Ω of a tolerance class at a specified temperature for a sensor.
+///
+/// The tolerance in °C is converted with the sensitivity of the sensor, so it honours the nominal
+/// resistance and curve of the sensor.
+///
+/// Allowed temperature range: depends on class and construction, see [`ToleranceClass::range`].
+#[allow(dead_code)]
+pub fn tolerance_r(
+    class: ToleranceClass,
+    construction: Construction,
+    t: f32,
+    sensor: &impl ResistiveSensor,
+) -> Result<f32, Error> {
+    Ok(tolerance_t(class, construction, t)? * sensor.sensitivity_at(t)?)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::RTDType;
+
+    #[test]
+    fn tolerance_classes() {
+        let wire = Construction::WireWound;
+        assert!(fabsf(tolerance_t(ToleranceClass::B, wire, 0_f32).unwrap() - 0.3) < 1e-6);
+        assert!(fabsf(tolerance_t(ToleranceClass::A, wire, -100_f32).unwrap() - 0.35) < 1e-6);
+        assert!(fabsf(tolerance_t(ToleranceClass::AA, wire, 250_f32).unwrap() - 0.525) < 1e-6);
+        assert!(fabsf(tolerance_t(ToleranceClass::C, wire, 600_f32).unwrap() - 6.6) < 1e-5);
+        assert!(fabsf(tolerance_t(ToleranceClass::ThirdDin, wire, 0_f32).unwrap() - 0.1) < 1e-6);
+        assert!(fabsf(tolerance_t(ToleranceClass::TenthDin, wire, 100_f32).unwrap() - 0.08) < 1e-6);
+
+        assert!(matches!(tolerance_t(ToleranceClass::AA, Construction::Film, -10_f32), Err(Error::OutOfRange { .. })));
+        assert!(matches!(tolerance_t(ToleranceClass::A, wire, 500_f32), Err(Error::OutOfRange { .. })));
+    }
+
+    #[test]
+    fn tolerance_in_ohm() {
+        // IEC 60751: class A at 0°C is ±0.06 Ω for a PT100
+        let tol = tolerance_r(ToleranceClass::A, Construction::WireWound, 0_f32, &RTDType::PT100).unwrap();
+        assert!(fabsf(tol - 0.0586) < 1e-3);
+        let tol = tolerance_r(ToleranceClass::B, Construction::WireWound, 100_f32, &RTDType::PT1000).unwrap();
+        assert!(fabsf(tol - 3.03) < 0.01);
+    }
+}
@@ -0,0 +1,143 @@
+//! Propagation of standard uncertainties through the resistance and temperature conversions.
+//!
+//! All inputs are treated as uncorrelated and combined according to the GUM (JCGM 100:2008) using
+//! first order sensitivity coefficients. For the ratiometric conversion
+//! R = d · R_ref / (res · G) the relative uncertainties of the digital value, the reference resistor
+//! and the PGA gain add in quadrature. The uncertainty of the resistance is converted to
+//! temperature with the magnitude of the sensitivity dR/dt of the sensor, which is negative for
+//! e.g. NTC thermistors.
+
+use libm::{
+    powf,
+    sqrtf,
+};
+
+use crate::{
+    conv_d_val_to_r as conv,
+    ADCRes,
+    Error,
+    ResistiveSensor,
+};
+
+/// Value together with its combined standard uncertainty, both in the same unit.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Measurement {
+    pub value: f32,
+    pub uncertainty: f32,
+}
+
+/// Standard uncertainties of the inputs of a ratiometric resistance measurement.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct AdcUncertainty {
+    /// Standard uncertainty of the reference resistor in Ω.
+    pub r_ref: f32,
+    /// RMS noise of the ADC in LSB. The quantisation uncertainty of 1/√12 LSB is added automatically.
+    pub noise: f32,
+    /// Relative standard uncertainty of the PGA gain.
+    pub pga_gain: f32,
+}
+
+/// Convert digital value of relative measurement for n bit ADC to resistance with its combined
+/// standard uncertainty in Ω.
+#[allow(dead_code)]
+pub fn conv_d_val_to_r(
+    d_val: u32,
+    r_ref: u32,
+    res: ADCRes,
+    pga_gain: u32,
+    u: &AdcUncertainty,
+) -> Result<Measurement, Error> {
+    let r = conv(d_val, r_ref, res, pga_gain)?;
+
+    // the resistance is proportional to each input, so its sensitivity coefficients are r / input
+    let u_d = sqrtf(u.noise * u.noise + 1_f32 / 12_f32);
+    let u_r = sqrtf(
+        powf(u_d * r_ref as f32 / ( res as u32 as f32 * pga_gain as f32 ), 2_f32)
+            + powf(r * u.r_ref / r_ref as f32, 2_f32)
+            + powf(r * u.pga_gain, 2_f32),
+    );
+    Ok(Measurement { value: r, uncertainty: u_r })
+}
+
+/// Calculate temperature of a sensor from a resistance value with its combined standard
+/// uncertainty in °C.
+#[allow(dead_code)]
+pub fn calc_t(r: Measurement, sensor: &impl ResistiveSensor) -> Result<Measurement, Error> {
+    let t = sensor.temperature_at(r.value)?;
+    Ok(Measurement { value: t, uncertainty: r.uncertainty / sensor.sensitivity_at(t)?.abs() })
+}
+
+/// Convert digital value of relative measurement for n bit ADC to temperature of a sensor with its
+/// combined standard uncertainty in °C.
+#[allow(dead_code)]
+pub fn conv_d_val_to_t(
+    d_val: u32,
+    r_ref: u32,
+    res: ADCRes,
+    pga_gain: u32,
+    u: &AdcUncertainty,
+    sensor: &impl ResistiveSensor,
+) -> Result<Measurement, Error> {
+    calc_t(conv_d_val_to_r(d_val, r_ref, res, pga_gain, u)?, sensor)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::RTDType;
+    use libm::fabsf;
+
+    #[test]
+    fn resistance_uncertainty() {
+        // only the reference resistor contributes significantly: 0.01 % of 400 Ω
+        let u = AdcUncertainty { r_ref: 0.04, noise: 0_f32, pga_gain: 0_f32 };
+        let r = conv_d_val_to_r(8_388_608, 400, ADCRes::B24, 1, &u).unwrap();
+        assert!(fabsf(r.value - 200_f32) < 1e-3);
+        assert!(fabsf(r.uncertainty - 0.02) < 1e-5);
+
+        // quantisation and noise of a 12 bit ADC: 1 LSB ≙ 400 Ω / 4095
+        let u = AdcUncertainty { r_ref: 0_f32, noise: 2_f32, pga_gain: 0_f32 };
+        let r = conv_d_val_to_r(1024, 400, ADCRes::B12, 1, &u).unwrap();
+        assert!(fabsf(r.uncertainty - sqrtf(4_f32 + 1_f32 / 12_f32) * 400_f32 / 4095_f32) < 1e-6);
+    }
+
+    #[test]
+    fn temperature_uncertainty() {
+        let u = AdcUncertainty { r_ref: 0.02, noise: 3_f32, pga_gain: 1e-5 };
+        let t = conv_d_val_to_t(4_194_304, 400, ADCRes::B24, 1, &u, &RTDType::PT100).unwrap();
+        assert!(fabsf(t.value) < 1e-3);
+        // 0.0051 Ω at 0.3908 Ω/K, dominated by the reference resistor
+        assert!(fabsf(t.uncertainty - 0.01305) < 1e-4);
+    }
+
+    #[test]
+    fn negative_sensitivity() {
+        use libm::{
+            expf,
+            logf,
+        };
+
+        /// NTC thermistor of 10 kΩ at 25°C with B = 3950 K.
+        struct Ntc;
+
+        impl ResistiveSensor for Ntc {
+            fn resistance_at(&self, t: f32) -> Result<f32, Error> {
+                Ok(10_000_f32 * expf(3950_f32 * ( 1_f32 / ( t + 273.15 ) - 1_f32 / 298.15 )))
+            }
+
+            fn temperature_at(&self, r: f32) -> Result<f32, Error> {
+                Ok(1_f32 / ( 1_f32 / 298.15 + logf(r / 10_000_f32) / 3950_f32 ) - 273.15)
+            }
+
+            fn valid_range(&self) -> (f32, f32) {
+                (-40_f32, 125_f32)
+            }
+        }
+
+        // dR/dt = -B · R / T² ≈ -444 Ω/K at 25°C
+        let t = calc_t(Measurement { value: 10_000_f32, uncertainty: 4.44 }, &Ntc).unwrap();
+        assert!(fabsf(t.value - 25_f32) < 1e-3);
+        assert!(t.uncertainty > 0_f32);
+        assert!(fabsf(t.uncertainty - 0.01) < 1e-4);
+    }
+}
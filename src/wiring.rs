@@ -0,0 +1,113 @@
+//! Lead resistance compensation for two-, three- and four-wire connections.
+//!
+//! A ratiometric measurement of a two-wire sensor includes the resistance of both leads, which adds
+//! about 2.6 K per Ω of lead resistance for a PT100. The compensation depends on the wiring:
+//!
+//! - Two-wire: the total resistance of both leads is subtracted. It is either known from the cable
+//!   or measured once with the sensor end of the cable shorted.
+//! - Three-wire: a second reading across the two leads connected to the same end of the element
+//!   gives the resistance of two leads, assuming all three leads are equal. It is subtracted from
+//!   the reading across the element.
+//! - Four-wire (Kelvin): the voltage is sensed by separate leads, the reading is the element
+//!   resistance.
+
+use crate::{
+    adc::{
+        self,
+        AdcFormat,
+    },
+    conv_d_val_to_r as conv,
+    ADCRes,
+    Error,
+    Quantity,
+    ResistiveSensor,
+};
+
+/// Wiring configuration of a sensor.
+#[non_exhaustive]
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Wiring {
+    /// Two-wire connection with the total resistance of both leads in Ω.
+    TwoWire { r_leads: f32 },
+    /// Three-wire connection with the code of the reading across the two leads at the same end of
+    /// the element, in the [`AdcFormat`] of the element reading.
+    ThreeWire { d_val_leads: i64 },
+    /// Four-wire (Kelvin) connection.
+    FourWire,
+}
+
+/// Convert digital value of relative measurement for n bit ADC to the resistance of the element
+/// in Ω, compensating the lead resistance of the wiring.
+///
+/// `d_val` is the reading across the element including its leads, see
+/// [`conv_d_val_to_r`](crate::conv_d_val_to_r). The same reference, resolution and PGA gain apply
+/// to the lead reading of a three-wire connection.
+#[allow(dead_code)]
+pub fn conv_d_val_to_r(d_val: u32, r_ref: u32, res: ADCRes, pga_gain: u32, wiring: Wiring) -> Result<f32, Error> {
+    let r = conv(d_val, r_ref, res, pga_gain)?;
+    let r = match wiring {
+        Wiring::TwoWire { r_leads } => r - r_leads,
+        Wiring::ThreeWire { d_val_leads } => r - adc::conv_d_val_to_r(d_val_leads, r_ref, AdcFormat::from(res), pga_gain)?,
+        Wiring::FourWire => r,
+    };
+    match r {
+        r if r >= 0_f32 => Ok(r),
+        r => Err(Error::out_of_range(Quantity::Resistance, r as f64, 0_f64, f64::INFINITY)),
+    }
+}
+
+/// Convert digital value of relative measurement for n bit ADC to temperature of a sensor in °C,
+/// compensating the lead resistance of the wiring.
+#[allow(dead_code)]
+pub fn conv_d_val_to_t(
+    d_val: u32,
+    r_ref: u32,
+    res: ADCRes,
+    pga_gain: u32,
+    wiring: Wiring,
+    sensor: &impl ResistiveSensor,
+) -> Result<f32, Error> {
+    sensor.temperature_at(conv_d_val_to_r(d_val, r_ref, res, pga_gain, wiring)?)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::RTDType;
+    use libm::fabsf;
+
+    /// Digital value of a 24 bit ADC with a 400 Ω reference for a resistance.
+    fn d_val(r: f32) -> u32 {
+        ( r / 400_f32 * ADCRes::B24 as u32 as f32 ) as u32
+    }
+
+    #[test]
+    fn lead_compensation() {
+        // PT100 at 0°C with 1 Ω per lead
+        let sensor = RTDType::PT100;
+        let wirings = [
+            (d_val(102_f32), Wiring::TwoWire { r_leads: 2_f32 }),
+            (d_val(102_f32), Wiring::ThreeWire { d_val_leads: d_val(2_f32) as i64 }),
+            (d_val(100_f32), Wiring::FourWire),
+        ];
+        for (d_val, wiring) in wirings {
+            let r = conv_d_val_to_r(d_val, 400, ADCRes::B24, 1, wiring).unwrap();
+            assert!(fabsf(r - 100_f32) < 1e-3);
+            let t = conv_d_val_to_t(d_val, 400, ADCRes::B24, 1, wiring, &sensor).unwrap();
+            assert!(fabsf(t) < 5e-3);
+        }
+
+        // uncompensated, the leads add about 5 K
+        let t = conv_d_val_to_t(d_val(102_f32), 400, ADCRes::B24, 1, Wiring::FourWire, &sensor).unwrap();
+        assert!(fabsf(t - 5.12) < 0.01);
+    }
+
+    #[test]
+    fn lead_resistance_above_reading() {
+        let wiring = Wiring::ThreeWire { d_val_leads: d_val(5_f32) as i64 };
+        assert!(matches!(
+            conv_d_val_to_r(d_val(4_f32), 400, ADCRes::B24, 1, wiring),
+            Err(Error::OutOfRange { quantity: Quantity::Resistance, .. }),
+        ));
+    }
+}